@@ -0,0 +1,58 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use parse_style::{Attribute, Color, Style};
+use std::hint::black_box;
+
+static STYLES: [&str; 8] = [
+    "bold red",
+    "italic blue on white",
+    "not bold dim Chartreuse1 on grey23",
+    "B I U S reverse #ff8700 on #1c1c1c",
+    "underline2 frame encircle overline color(208) on default",
+    "rgb(10,20,30) on rgb(200,100,50) not blink not conceal",
+    "none",
+    "",
+];
+
+static COLORS: [&str; 6] = [
+    "default",
+    "red",
+    "MediumPurple4",
+    "color(236)",
+    "#ff8700",
+    "rgb(10,20,30)",
+];
+
+static ATTRIBUTES: [&str; 6] = ["bold", "B", "Italic", "underline2", "uu", "OVERLINE"];
+
+fn parse_style(c: &mut Criterion) {
+    c.bench_function("parse Style", |b| {
+        b.iter(|| {
+            for s in STYLES {
+                let _ = black_box(black_box(s).parse::<Style>());
+            }
+        });
+    });
+}
+
+fn parse_color(c: &mut Criterion) {
+    c.bench_function("parse Color", |b| {
+        b.iter(|| {
+            for s in COLORS {
+                let _ = black_box(black_box(s).parse::<Color>());
+            }
+        });
+    });
+}
+
+fn parse_attribute(c: &mut Criterion) {
+    c.bench_function("parse Attribute", |b| {
+        b.iter(|| {
+            for s in ATTRIBUTES {
+                let _ = black_box(black_box(s).parse::<Attribute>());
+            }
+        });
+    });
+}
+
+criterion_group!(benches, parse_style, parse_color, parse_attribute);
+criterion_main!(benches);
@@ -0,0 +1,455 @@
+//! Parsing text containing ANSI escape sequences
+//!
+//! This module provides an incremental tokenizer, [`AnsiParser`], that splits
+//! a stream of bytes — such as the captured output of a child process — into
+//! runs of text and escape sequences.  SGR ("Select Graphic Rendition")
+//! sequences are interpreted, and each run of text is paired with the
+//! effective [`Style`] that it is displayed in.
+//!
+//! For the common case of a complete string, see [`strip_ansi()`] and
+//! [`parse_ansi()`].
+use crate::sgr::{apply_sgr_params, effective, split_sgr_params};
+use crate::style::Style;
+use crate::text::StyledText;
+
+const ESC: u8 = 0x1B;
+const BEL: u8 = 0x07;
+
+/// A token produced by an [`AnsiParser`]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AnsiToken {
+    /// A run of text, along with the effective style that it is displayed in
+    ///
+    /// Invalid UTF-8 is replaced with U+FFFD REPLACEMENT CHARACTER.
+    Text {
+        /// The text
+        text: String,
+
+        /// The effective style of the text
+        style: Style,
+    },
+
+    /// An SGR sequence, along with the effective style after applying it
+    Sgr(Style),
+
+    /// Any other escape sequence (e.g., a non-SGR CSI sequence or an OSC
+    /// sequence), given verbatim, including the leading `ESC`.  Sequences
+    /// that were interrupted or left unterminated are also reported this way.
+    Escape(Vec<u8>),
+}
+
+/// An incremental tokenizer for text containing ANSI escape sequences
+///
+/// Feed chunks of input to the parser with [`AnsiParser::feed()`]; escape
+/// sequences and UTF-8 characters may be split across chunks.  Once the input
+/// is exhausted, call [`AnsiParser::finish()`] to obtain any remaining
+/// tokens.
+///
+/// The parser tracks the cumulative effect of the SGR sequences it has seen,
+/// starting from an empty style: an SGR sequence is applied on top of the
+/// current style, with parameter 0 resetting it.  Effective styles never
+/// contain disabled attributes or [`Color::Default`][crate::Color::Default],
+/// as these are equivalent to leaving the attribute or color unset.
+///
+/// SGR sequences that use colon-separated subparameters are not interpreted
+/// and are instead reported as [`AnsiToken::Escape`] tokens.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style};
+/// use parse_style::ansi::{AnsiParser, AnsiToken};
+///
+/// let mut parser = AnsiParser::new();
+/// let mut tokens = parser.feed(b"plain \x1b[1;3");
+/// tokens.extend(parser.feed(b"1mred\x1b[0m"));
+/// tokens.extend(parser.finish());
+/// assert_eq!(
+///     tokens,
+///     [
+///         AnsiToken::Text {
+///             text: "plain ".into(),
+///             style: Style::new()
+///         },
+///         AnsiToken::Sgr(Color256::RED.as_foreground().bold()),
+///         AnsiToken::Text {
+///             text: "red".into(),
+///             style: Color256::RED.as_foreground().bold()
+///         },
+///         AnsiToken::Sgr(Style::new()),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AnsiParser {
+    state: State,
+    text: Vec<u8>,
+    seq: Vec<u8>,
+    style: Style,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum State {
+    #[default]
+    Ground,
+    /// After an `ESC`
+    Escape,
+    /// After an `ESC` followed by one or more intermediate bytes
+    EscIntermediate,
+    /// Inside a CSI sequence
+    Csi,
+    /// Inside an OSC, DCS, SOS, PM, or APC string; `bel` is true iff the
+    /// string may be terminated by BEL (i.e., iff it is an OSC string)
+    Str { bel: bool },
+    /// After an `ESC` inside a string
+    StrEsc,
+}
+
+impl AnsiParser {
+    /// Create a new `AnsiParser` with an empty current style
+    pub fn new() -> AnsiParser {
+        AnsiParser::default()
+    }
+
+    /// Return the current effective style
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Process a chunk of input and return the tokens completed by it
+    ///
+    /// Any text at the end of the chunk is returned immediately, except for
+    /// an incomplete UTF-8 sequence, which is held until the next chunk.  An
+    /// incomplete escape sequence is likewise held until it is complete.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<AnsiToken> {
+        let mut tokens = Vec::new();
+        for &b in chunk {
+            self.advance(b, &mut tokens);
+        }
+        self.flush_text(false, &mut tokens);
+        tokens
+    }
+
+    /// Signal the end of input and return any remaining tokens
+    ///
+    /// A trailing incomplete UTF-8 sequence is replaced with U+FFFD
+    /// REPLACEMENT CHARACTER, and a trailing incomplete escape sequence is
+    /// returned as an [`AnsiToken::Escape`].  The parser's current style is
+    /// left unchanged.
+    pub fn finish(&mut self) -> Vec<AnsiToken> {
+        let mut tokens = Vec::new();
+        self.flush_text(true, &mut tokens);
+        if !self.seq.is_empty() {
+            tokens.push(AnsiToken::Escape(std::mem::take(&mut self.seq)));
+        }
+        self.state = State::Ground;
+        tokens
+    }
+
+    fn advance(&mut self, b: u8, tokens: &mut Vec<AnsiToken>) {
+        match self.state {
+            State::Ground => {
+                if b == ESC {
+                    self.flush_text(true, tokens);
+                    self.seq.push(b);
+                    self.state = State::Escape;
+                } else {
+                    self.text.push(b);
+                }
+            }
+            State::Escape => match b {
+                b'[' => self.continue_seq(b, State::Csi),
+                b']' => self.continue_seq(b, State::Str { bel: true }),
+                b'P' | b'X' | b'^' | b'_' => self.continue_seq(b, State::Str { bel: false }),
+                0x20..=0x2F => self.continue_seq(b, State::EscIntermediate),
+                0x30..=0x7E => self.end_seq(b, tokens),
+                _ => self.abort_seq(b, tokens),
+            },
+            State::EscIntermediate => match b {
+                0x20..=0x2F => self.seq.push(b),
+                0x30..=0x7E => self.end_seq(b, tokens),
+                _ => self.abort_seq(b, tokens),
+            },
+            State::Csi => match b {
+                0x20..=0x3F => self.seq.push(b),
+                0x40..=0x7E => self.end_seq(b, tokens),
+                _ => self.abort_seq(b, tokens),
+            },
+            State::Str { bel } => match b {
+                BEL if bel => self.end_seq(b, tokens),
+                ESC => self.continue_seq(b, State::StrEsc),
+                _ => self.seq.push(b),
+            },
+            State::StrEsc => {
+                if b == b'\\' {
+                    self.end_seq(b, tokens);
+                } else {
+                    // The ESC did not start a string terminator, so the
+                    // string is unterminated, and the ESC begins a new
+                    // sequence.
+                    self.seq.pop();
+                    tokens.push(AnsiToken::Escape(std::mem::take(&mut self.seq)));
+                    self.seq.push(ESC);
+                    self.state = State::Escape;
+                    self.advance(b, tokens);
+                }
+            }
+        }
+    }
+
+    fn continue_seq(&mut self, b: u8, state: State) {
+        self.seq.push(b);
+        self.state = state;
+    }
+
+    fn end_seq(&mut self, b: u8, tokens: &mut Vec<AnsiToken>) {
+        self.seq.push(b);
+        self.state = State::Ground;
+        let seq = std::mem::take(&mut self.seq);
+        match sgr_params(&seq) {
+            Some(params) => {
+                self.style = effective(apply_sgr_params(self.style, split_sgr_params(params)));
+                tokens.push(AnsiToken::Sgr(self.style));
+            }
+            None => tokens.push(AnsiToken::Escape(seq)),
+        }
+    }
+
+    /// Called when byte `b` cannot be part of the current escape sequence:
+    /// report the sequence so far as-is and process `b` normally
+    fn abort_seq(&mut self, b: u8, tokens: &mut Vec<AnsiToken>) {
+        tokens.push(AnsiToken::Escape(std::mem::take(&mut self.seq)));
+        self.state = State::Ground;
+        self.advance(b, tokens);
+    }
+
+    /// Emit the pending text as a token.  If `at_end` is false, an incomplete
+    /// UTF-8 sequence at the end of the text is held back.
+    fn flush_text(&mut self, at_end: bool, tokens: &mut Vec<AnsiToken>) {
+        let bytes = std::mem::take(&mut self.text);
+        let mut text = String::new();
+        let mut rest = &bytes[..];
+        while !rest.is_empty() {
+            match std::str::from_utf8(rest) {
+                Ok(s) => {
+                    text.push_str(s);
+                    rest = &[];
+                }
+                Err(e) => {
+                    let (valid, after) = rest.split_at(e.valid_up_to());
+                    text.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                    match e.error_len() {
+                        Some(len) => {
+                            text.push(char::REPLACEMENT_CHARACTER);
+                            rest = after.get(len..).unwrap_or_default();
+                        }
+                        None if at_end => {
+                            text.push(char::REPLACEMENT_CHARACTER);
+                            rest = &[];
+                        }
+                        None => {
+                            self.text = after.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        if !text.is_empty() {
+            tokens.push(AnsiToken::Text {
+                text,
+                style: self.style,
+            });
+        }
+    }
+}
+
+/// If `seq` is an SGR sequence consisting of only digits and semicolons,
+/// return the parameter string
+fn sgr_params(seq: &[u8]) -> Option<&str> {
+    let params = seq.strip_prefix(b"\x1B[")?.strip_suffix(b"m")?;
+    if params.iter().all(|&b| b.is_ascii_digit() || b == b';') {
+        std::str::from_utf8(params).ok()
+    } else {
+        None
+    }
+}
+
+/// Remove all ANSI escape sequences from a string
+///
+/// # Example
+///
+/// ```
+/// use parse_style::ansi::strip_ansi;
+///
+/// assert_eq!(
+///     strip_ansi("\x1b[1;31mError:\x1b[0m \x1b]8;;https://example.com\x07link\x1b]8;;\x07"),
+///     "Error: link"
+/// );
+/// ```
+pub fn strip_ansi(s: &str) -> String {
+    let mut parser = AnsiParser::new();
+    let mut tokens = parser.feed(s.as_bytes());
+    tokens.extend(parser.finish());
+    tokens
+        .into_iter()
+        .filter_map(|t| match t {
+            AnsiToken::Text { text, .. } => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parse a string containing ANSI escape sequences into a [`StyledText`]
+/// whose spans reflect the effective styles of the text.  Escape sequences
+/// other than SGR sequences are discarded.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style};
+/// use parse_style::ansi::parse_ansi;
+///
+/// let text = parse_ansi("\x1b[1mbold\x1b[22;34m blue\x1b[m plain");
+/// assert_eq!(text.as_str(), "bold blue plain");
+/// assert_eq!(
+///     text.segments().collect::<Vec<_>>(),
+///     [
+///         ("bold", Style::new().bold()),
+///         (" blue", Style::from(Color256::BLUE)),
+///         (" plain", Style::new()),
+///     ]
+/// );
+/// ```
+pub fn parse_ansi(s: &str) -> StyledText {
+    let mut parser = AnsiParser::new();
+    let mut tokens = parser.feed(s.as_bytes());
+    tokens.extend(parser.finish());
+    tokens
+        .into_iter()
+        .filter_map(|t| match t {
+            AnsiToken::Text { text, style } => Some((text, style)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color256;
+    use rstest::rstest;
+
+    fn tokenize(chunks: &[&[u8]]) -> Vec<AnsiToken> {
+        let mut parser = AnsiParser::new();
+        let mut tokens = Vec::new();
+        for chunk in chunks {
+            tokens.extend(parser.feed(chunk));
+        }
+        tokens.extend(parser.finish());
+        tokens
+    }
+
+    fn text(s: &str, style: Style) -> AnsiToken {
+        AnsiToken::Text {
+            text: s.into(),
+            style,
+        }
+    }
+
+    #[test]
+    fn cumulative() {
+        let red = Style::from(Color256::RED);
+        assert_eq!(
+            tokenize(&[b"\x1b[31ma\x1b[1mb\x1b[22;39mc"]),
+            [
+                AnsiToken::Sgr(red),
+                text("a", red),
+                AnsiToken::Sgr(red.bold()),
+                text("b", red.bold()),
+                AnsiToken::Sgr(Style::new()),
+                text("c", Style::new()),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(b"\x1b[2J")]
+    #[case(b"\x1b[?25l")]
+    #[case(b"\x1b[4:3m")]
+    #[case(b"\x1b]0;title\x07")]
+    #[case(b"\x1b]0;title\x1b\\")]
+    #[case(b"\x1bP1$r\x1b\\")]
+    #[case(b"\x1b(B")]
+    #[case(b"\x1b7")]
+    fn passthrough(#[case] seq: &[u8]) {
+        let mut input = b"a".to_vec();
+        input.extend_from_slice(seq);
+        input.push(b'b');
+        assert_eq!(
+            tokenize(&[&input]),
+            [
+                text("a", Style::new()),
+                AnsiToken::Escape(seq.to_vec()),
+                text("b", Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_escape() {
+        let bold = Style::new().bold();
+        assert_eq!(
+            tokenize(&[b"x\x1b", b"[", b"1", b"my"]),
+            [
+                text("x", Style::new()),
+                AnsiToken::Sgr(bold),
+                text("y", bold)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_utf8() {
+        let (start, end) = "¡olé!".as_bytes().split_at(5);
+        let (start, middle) = start.split_at(1);
+        assert_eq!(
+            tokenize(&[start, middle, end]),
+            [text("¡ol", Style::new()), text("é!", Style::new()),]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            tokenize(&[b"a\xFFb\xC3", b"\x1b[m\xE2\x82"]),
+            [
+                text("a\u{FFFD}b", Style::new()),
+                text("\u{FFFD}", Style::new()),
+                AnsiToken::Sgr(Style::new()),
+                text("\u{FFFD}", Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn interrupted() {
+        assert_eq!(
+            tokenize(&[b"\x1b[1\nx\x1b]0;title\x1b[1mbold\x1b["]),
+            [
+                AnsiToken::Escape(b"\x1b[1".to_vec()),
+                text("\nx", Style::new()),
+                AnsiToken::Escape(b"\x1b]0;title".to_vec()),
+                AnsiToken::Sgr(Style::new().bold()),
+                text("bold", Style::new().bold()),
+                AnsiToken::Escape(b"\x1b[".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn strip() {
+        assert_eq!(strip_ansi("no escapes"), "no escapes");
+        assert_eq!(strip_ansi("\x1b[38;5;208mor\x1b[Kange\x1b[0m"), "orange");
+    }
+}
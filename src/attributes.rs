@@ -114,6 +114,31 @@ impl Attribute {
             Attribute::Overline => "overline",
         }
     }
+
+    /// Parse an attribute name case-insensitively without allocating
+    pub(crate) fn parse_str(s: &str) -> Option<Attribute> {
+        // Long enough for the longest name, "underline2"
+        let mut buf = [0u8; 10];
+        let name = buf.get_mut(..s.len())?;
+        name.copy_from_slice(s.as_bytes());
+        name.make_ascii_lowercase();
+        match &*name {
+            b"bold" | b"b" => Some(Attribute::Bold),
+            b"dim" | b"d" => Some(Attribute::Dim),
+            b"italic" | b"i" => Some(Attribute::Italic),
+            b"underline" | b"u" => Some(Attribute::Underline),
+            b"blink" => Some(Attribute::Blink),
+            b"blink2" => Some(Attribute::Blink2),
+            b"reverse" | b"r" => Some(Attribute::Reverse),
+            b"conceal" | b"c" => Some(Attribute::Conceal),
+            b"strike" | b"s" => Some(Attribute::Strike),
+            b"underline2" | b"uu" => Some(Attribute::Underline2),
+            b"frame" => Some(Attribute::Frame),
+            b"encircle" => Some(Attribute::Encircle),
+            b"overline" => Some(Attribute::Overline),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Attribute {
@@ -130,22 +155,7 @@ impl std::str::FromStr for Attribute {
     type Err = ParseAttributeError;
 
     fn from_str(s: &str) -> Result<Attribute, ParseAttributeError> {
-        match s.to_ascii_lowercase().as_str() {
-            "bold" | "b" => Ok(Attribute::Bold),
-            "dim" | "d" => Ok(Attribute::Dim),
-            "italic" | "i" => Ok(Attribute::Italic),
-            "underline" | "u" => Ok(Attribute::Underline),
-            "blink" => Ok(Attribute::Blink),
-            "blink2" => Ok(Attribute::Blink2),
-            "reverse" | "r" => Ok(Attribute::Reverse),
-            "conceal" | "c" => Ok(Attribute::Conceal),
-            "strike" | "s" => Ok(Attribute::Strike),
-            "underline2" | "uu" => Ok(Attribute::Underline2),
-            "frame" => Ok(Attribute::Frame),
-            "encircle" => Ok(Attribute::Encircle),
-            "overline" => Ok(Attribute::Overline),
-            _ => Err(ParseAttributeError(s.to_owned())),
-        }
+        Attribute::parse_str(s).ok_or_else(|| ParseAttributeError(s.to_owned()))
     }
 }
 
@@ -483,6 +493,120 @@ impl From<anstyle::Effects> for AttributeSet {
     }
 }
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<AttributeSet> for colored::Style {
+    /// Convert an `AttributeSet` to a [`colored::Style`]
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion, as they have
+    /// no `colored::Styles` equivalents:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn from(value: AttributeSet) -> colored::Style {
+        let mut style = colored::Style::default();
+        for attr in value {
+            match attr {
+                Attribute::Bold => style.add(colored::Styles::Bold),
+                Attribute::Dim => style.add(colored::Styles::Dimmed),
+                Attribute::Italic => style.add(colored::Styles::Italic),
+                Attribute::Underline => style.add(colored::Styles::Underline),
+                Attribute::Blink => style.add(colored::Styles::Blink),
+                Attribute::Blink2 => (),
+                Attribute::Reverse => style.add(colored::Styles::Reversed),
+                Attribute::Conceal => style.add(colored::Styles::Hidden),
+                Attribute::Strike => style.add(colored::Styles::Strikethrough),
+                Attribute::Underline2 => (),
+                Attribute::Frame => (),
+                Attribute::Encircle => (),
+                Attribute::Overline => (),
+            }
+        }
+        style
+    }
+}
+
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<colored::Style> for AttributeSet {
+    /// Convert a [`colored::Style`] to an `AttributeSet`
+    fn from(value: colored::Style) -> AttributeSet {
+        [
+            (colored::Styles::Bold, Attribute::Bold),
+            (colored::Styles::Dimmed, Attribute::Dim),
+            (colored::Styles::Italic, Attribute::Italic),
+            (colored::Styles::Underline, Attribute::Underline),
+            (colored::Styles::Blink, Attribute::Blink),
+            (colored::Styles::Reversed, Attribute::Reverse),
+            (colored::Styles::Hidden, Attribute::Conceal),
+            (colored::Styles::Strikethrough, Attribute::Strike),
+        ]
+        .into_iter()
+        .filter(|&(st, _)| value.contains(st))
+        .map(|(_, attr)| attr)
+        .collect()
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl TryFrom<Attribute> for console::Attribute {
+    type Error = crate::ConversionError;
+
+    /// Convert an `Attribute` to a [`console::Attribute`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is one of the following attributes, which have
+    /// no `console::Attribute` equivalents:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn try_from(value: Attribute) -> Result<console::Attribute, crate::ConversionError> {
+        match value {
+            Attribute::Bold => Ok(console::Attribute::Bold),
+            Attribute::Dim => Ok(console::Attribute::Dim),
+            Attribute::Italic => Ok(console::Attribute::Italic),
+            Attribute::Underline => Ok(console::Attribute::Underlined),
+            Attribute::Blink => Ok(console::Attribute::Blink),
+            Attribute::Blink2 => Ok(console::Attribute::BlinkFast),
+            Attribute::Reverse => Ok(console::Attribute::Reverse),
+            Attribute::Conceal => Ok(console::Attribute::Hidden),
+            Attribute::Strike => Ok(console::Attribute::StrikeThrough),
+            Attribute::Underline2 => Err(crate::ConversionError),
+            Attribute::Frame => Err(crate::ConversionError),
+            Attribute::Encircle => Err(crate::ConversionError),
+            Attribute::Overline => Err(crate::ConversionError),
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl From<console::Attribute> for Attribute {
+    /// Convert a [`console::Attribute`] to an `Attribute`
+    fn from(value: console::Attribute) -> Attribute {
+        match value {
+            console::Attribute::Bold => Attribute::Bold,
+            console::Attribute::Dim => Attribute::Dim,
+            console::Attribute::Italic => Attribute::Italic,
+            console::Attribute::Underlined => Attribute::Underline,
+            console::Attribute::Blink => Attribute::Blink,
+            console::Attribute::BlinkFast => Attribute::Blink2,
+            console::Attribute::Reverse => Attribute::Reverse,
+            console::Attribute::Hidden => Attribute::Conceal,
+            console::Attribute::StrikeThrough => Attribute::Strike,
+        }
+    }
+}
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 impl From<AttributeSet> for crossterm::style::Attributes {
@@ -513,6 +637,185 @@ impl From<AttributeSet> for crossterm::style::Attributes {
     }
 }
 
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl TryFrom<Attribute> for cursive_core::style::Effect {
+    type Error = crate::ConversionError;
+
+    /// Convert an `Attribute` to a [`cursive_core::style::Effect`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is one of the following attributes, which have
+    /// no `cursive_core::style::Effect` equivalents:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Conceal`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn try_from(value: Attribute) -> Result<cursive_core::style::Effect, crate::ConversionError> {
+        match value {
+            Attribute::Bold => Ok(cursive_core::style::Effect::Bold),
+            Attribute::Dim => Ok(cursive_core::style::Effect::Dim),
+            Attribute::Italic => Ok(cursive_core::style::Effect::Italic),
+            Attribute::Underline => Ok(cursive_core::style::Effect::Underline),
+            Attribute::Blink => Ok(cursive_core::style::Effect::Blink),
+            Attribute::Blink2 => Err(crate::ConversionError),
+            Attribute::Reverse => Ok(cursive_core::style::Effect::Reverse),
+            Attribute::Conceal => Err(crate::ConversionError),
+            Attribute::Strike => Ok(cursive_core::style::Effect::Strikethrough),
+            Attribute::Underline2 => Err(crate::ConversionError),
+            Attribute::Frame => Err(crate::ConversionError),
+            Attribute::Encircle => Err(crate::ConversionError),
+            Attribute::Overline => Err(crate::ConversionError),
+        }
+    }
+}
+
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl TryFrom<cursive_core::style::Effect> for Attribute {
+    type Error = crate::ConversionError;
+
+    /// Convert a [`cursive_core::style::Effect`] to an `Attribute`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is [`cursive_core::style::Effect::Simple`],
+    /// which has no `Attribute` equivalent
+    fn try_from(value: cursive_core::style::Effect) -> Result<Attribute, crate::ConversionError> {
+        match value {
+            cursive_core::style::Effect::Simple => Err(crate::ConversionError),
+            cursive_core::style::Effect::Reverse => Ok(Attribute::Reverse),
+            cursive_core::style::Effect::Dim => Ok(Attribute::Dim),
+            cursive_core::style::Effect::Bold => Ok(Attribute::Bold),
+            cursive_core::style::Effect::Italic => Ok(Attribute::Italic),
+            cursive_core::style::Effect::Strikethrough => Ok(Attribute::Strike),
+            cursive_core::style::Effect::Underline => Ok(Attribute::Underline),
+            cursive_core::style::Effect::Blink => Ok(Attribute::Blink),
+        }
+    }
+}
+
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<AttributeSet> for cursive_core::style::Effects {
+    /// Convert an `AttributeSet` to a [`cursive_core::style::Effects`] that
+    /// turns on the input attributes and inherits all other effects from the
+    /// parent style
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion, as they have
+    /// no `cursive_core::style::Effect` equivalents:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Conceal`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn from(value: AttributeSet) -> cursive_core::style::Effects {
+        let mut effects = cursive_core::style::Effects::empty();
+        for effect in value
+            .into_iter()
+            .filter_map(|attr| cursive_core::style::Effect::try_from(attr).ok())
+        {
+            effects.statuses[effect] = cursive_core::style::EffectStatus::On;
+        }
+        effects
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<AttributeSet> for nu_ansi_term::Style {
+    /// Convert an `AttributeSet` to a [`nu_ansi_term::Style`] that enables
+    /// the input attributes and has no colors
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion, as they have
+    /// no `nu_ansi_term::Style` equivalents:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn from(value: AttributeSet) -> nu_ansi_term::Style {
+        nu_ansi_term::Style {
+            foreground: None,
+            background: None,
+            is_bold: value.contains(Attribute::Bold),
+            is_dimmed: value.contains(Attribute::Dim),
+            is_italic: value.contains(Attribute::Italic),
+            is_underline: value.contains(Attribute::Underline),
+            is_blink: value.contains(Attribute::Blink),
+            is_reverse: value.contains(Attribute::Reverse),
+            is_hidden: value.contains(Attribute::Conceal),
+            is_strikethrough: value.contains(Attribute::Strike),
+            prefix_with_reset: false,
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl TryFrom<Attribute> for owo_colors::Effect {
+    type Error = crate::ConversionError;
+
+    /// Convert an `Attribute` to an [`owo_colors::Effect`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is one of the following attributes, which have
+    /// no `owo_colors::Effect` equivalents:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn try_from(value: Attribute) -> Result<owo_colors::Effect, crate::ConversionError> {
+        match value {
+            Attribute::Bold => Ok(owo_colors::Effect::Bold),
+            Attribute::Dim => Ok(owo_colors::Effect::Dimmed),
+            Attribute::Italic => Ok(owo_colors::Effect::Italic),
+            Attribute::Underline => Ok(owo_colors::Effect::Underline),
+            Attribute::Blink => Ok(owo_colors::Effect::Blink),
+            Attribute::Blink2 => Ok(owo_colors::Effect::BlinkFast),
+            Attribute::Reverse => Ok(owo_colors::Effect::Reversed),
+            Attribute::Conceal => Ok(owo_colors::Effect::Hidden),
+            Attribute::Strike => Ok(owo_colors::Effect::Strikethrough),
+            Attribute::Underline2 => Err(crate::ConversionError),
+            Attribute::Frame => Err(crate::ConversionError),
+            Attribute::Encircle => Err(crate::ConversionError),
+            Attribute::Overline => Err(crate::ConversionError),
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<owo_colors::Effect> for Attribute {
+    /// Convert an [`owo_colors::Effect`] to an `Attribute`
+    fn from(value: owo_colors::Effect) -> Attribute {
+        match value {
+            owo_colors::Effect::Bold => Attribute::Bold,
+            owo_colors::Effect::Dimmed => Attribute::Dim,
+            owo_colors::Effect::Italic => Attribute::Italic,
+            owo_colors::Effect::Underline => Attribute::Underline,
+            owo_colors::Effect::Blink => Attribute::Blink,
+            owo_colors::Effect::BlinkFast => Attribute::Blink2,
+            owo_colors::Effect::Reversed => Attribute::Reverse,
+            owo_colors::Effect::Hidden => Attribute::Conceal,
+            owo_colors::Effect::Strikethrough => Attribute::Strike,
+        }
+    }
+}
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
 impl From<AttributeSet> for ratatui_core::style::Modifier {
@@ -577,6 +880,129 @@ impl From<ratatui_core::style::Modifier> for AttributeSet {
     }
 }
 
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<AttributeSet> for syntect::highlighting::FontStyle {
+    /// Convert an `AttributeSet` to a [`syntect::highlighting::FontStyle`]
+    ///
+    /// # Data Loss
+    ///
+    /// All attributes other than [`Attribute::Bold`], [`Attribute::Italic`],
+    /// and [`Attribute::Underline`] are discarded during conversion, as they
+    /// have no `syntect::highlighting::FontStyle` equivalents.
+    fn from(value: AttributeSet) -> syntect::highlighting::FontStyle {
+        use syntect::highlighting::FontStyle;
+        let mut fs = FontStyle::empty();
+        if value.contains(Attribute::Bold) {
+            fs |= FontStyle::BOLD;
+        }
+        if value.contains(Attribute::Italic) {
+            fs |= FontStyle::ITALIC;
+        }
+        if value.contains(Attribute::Underline) {
+            fs |= FontStyle::UNDERLINE;
+        }
+        fs
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<syntect::highlighting::FontStyle> for AttributeSet {
+    /// Convert a [`syntect::highlighting::FontStyle`] to an `AttributeSet`
+    fn from(value: syntect::highlighting::FontStyle) -> AttributeSet {
+        use syntect::highlighting::FontStyle;
+        let mut set = AttributeSet::new();
+        if value.contains(FontStyle::BOLD) {
+            set |= Attribute::Bold;
+        }
+        if value.contains(FontStyle::ITALIC) {
+            set |= Attribute::Italic;
+        }
+        if value.contains(FontStyle::UNDERLINE) {
+            set |= Attribute::Underline;
+        }
+        set
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl TryFrom<Attribute> for yansi::Attribute {
+    type Error = crate::ConversionError;
+
+    /// Convert an `Attribute` to a [`yansi::Attribute`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is one of the following attributes, which have
+    /// no `yansi::Attribute` equivalents:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn try_from(value: Attribute) -> Result<yansi::Attribute, crate::ConversionError> {
+        match value {
+            Attribute::Bold => Ok(yansi::Attribute::Bold),
+            Attribute::Dim => Ok(yansi::Attribute::Dim),
+            Attribute::Italic => Ok(yansi::Attribute::Italic),
+            Attribute::Underline => Ok(yansi::Attribute::Underline),
+            Attribute::Blink => Ok(yansi::Attribute::Blink),
+            Attribute::Blink2 => Ok(yansi::Attribute::RapidBlink),
+            Attribute::Reverse => Ok(yansi::Attribute::Invert),
+            Attribute::Conceal => Ok(yansi::Attribute::Conceal),
+            Attribute::Strike => Ok(yansi::Attribute::Strike),
+            Attribute::Underline2 => Err(crate::ConversionError),
+            Attribute::Frame => Err(crate::ConversionError),
+            Attribute::Encircle => Err(crate::ConversionError),
+            Attribute::Overline => Err(crate::ConversionError),
+        }
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<yansi::Attribute> for Attribute {
+    /// Convert a [`yansi::Attribute`] to an `Attribute`
+    fn from(value: yansi::Attribute) -> Attribute {
+        match value {
+            yansi::Attribute::Bold => Attribute::Bold,
+            yansi::Attribute::Dim => Attribute::Dim,
+            yansi::Attribute::Italic => Attribute::Italic,
+            yansi::Attribute::Underline => Attribute::Underline,
+            yansi::Attribute::Blink => Attribute::Blink,
+            yansi::Attribute::RapidBlink => Attribute::Blink2,
+            yansi::Attribute::Invert => Attribute::Reverse,
+            yansi::Attribute::Conceal => Attribute::Conceal,
+            yansi::Attribute::Strike => Attribute::Strike,
+        }
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<AttributeSet> for yansi::Style {
+    /// Convert an `AttributeSet` to a [`yansi::Style`] that enables the input
+    /// attributes and has no colors
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion, as they have
+    /// no `yansi::Attribute` equivalents:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn from(value: AttributeSet) -> yansi::Style {
+        value
+            .into_iter()
+            .filter_map(|attr| yansi::Attribute::try_from(attr).ok())
+            .fold(yansi::Style::new(), yansi::Style::attr)
+    }
+}
+
 impl<A: Into<AttributeSet>> std::ops::BitAnd<A> for AttributeSet {
     type Output = AttributeSet;
 
@@ -728,6 +1154,28 @@ mod tests {
         fn alt_display(#[case] attr: Attribute, #[case] s: &str) {
             assert_eq!(format!("{attr:#}"), s);
         }
+
+        #[test]
+        fn parse_names() {
+            for attr in Attribute::iter() {
+                for name in [attr.as_str(), attr.as_short_str()] {
+                    assert_eq!(name.parse::<Attribute>(), Ok(attr));
+                    assert_eq!(name.to_ascii_uppercase().parse::<Attribute>(), Ok(attr));
+                }
+            }
+        }
+
+        #[rstest]
+        #[case("")]
+        #[case("bolder")]
+        #[case("uuu")]
+        #[case("not")]
+        fn parse_err(#[case] s: &str) {
+            assert_eq!(
+                s.parse::<Attribute>(),
+                Err(ParseAttributeError(s.to_owned()))
+            );
+        }
     }
 
     mod attribute_set {
@@ -1,7 +1,9 @@
 use super::ParseColorError;
 use crate::color256::Color256;
-use crate::rgbcolor::RgbColor;
+use crate::palette::Palette;
+use crate::rgbcolor::{Gradient, RgbColor};
 use crate::style::Style;
+use crate::util::strip_nocase_prefix;
 use std::fmt;
 
 /// An enum of the different color types
@@ -15,6 +17,78 @@ pub enum Color {
 }
 
 impl Color {
+    /// Convert the color to an [`RgbColor`], using `palette` to resolve
+    /// [`Color256`] values.
+    ///
+    /// Returns `None` for [`Color::Default`], as the RGB value of the
+    /// terminal's default color is not known.
+    pub fn to_rgb(self, palette: &Palette) -> Option<RgbColor> {
+        match self {
+            Color::Default => None,
+            Color::Color256(c) => Some(palette.get(c)),
+            Color::Rgb(c) => Some(c),
+        }
+    }
+
+    /// Mix this color with `other` as by [`RgbColor::blend()`], using
+    /// `palette` to resolve [`Color256`] values.
+    ///
+    /// Returns `None` if either color is [`Color::Default`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Color, Color256, Palette, RgbColor};
+    ///
+    /// let red = Color::from(Color256::BRIGHT_RED);
+    /// let blue = Color::from(Color256::BRIGHT_BLUE);
+    /// assert_eq!(
+    ///     red.blend(blue, 0.5, &Palette::XTERM),
+    ///     Some(RgbColor(128, 0, 128))
+    /// );
+    /// assert_eq!(red.blend(Color::Default, 0.5, &Palette::XTERM), None);
+    /// ```
+    pub fn blend(self, other: Color, t: f64, palette: &Palette) -> Option<RgbColor> {
+        Some(self.to_rgb(palette)?.blend(other.to_rgb(palette)?, t))
+    }
+
+    /// Mix this color with white as by [`RgbColor::lighten()`], using
+    /// `palette` to resolve [`Color256`] values.
+    ///
+    /// Returns `None` for [`Color::Default`].
+    pub fn lighten(self, amount: f64, palette: &Palette) -> Option<RgbColor> {
+        self.to_rgb(palette).map(|c| c.lighten(amount))
+    }
+
+    /// Mix this color with black as by [`RgbColor::darken()`], using
+    /// `palette` to resolve [`Color256`] values.
+    ///
+    /// Returns `None` for [`Color::Default`].
+    pub fn darken(self, amount: f64, palette: &Palette) -> Option<RgbColor> {
+        self.to_rgb(palette).map(|c| c.darken(amount))
+    }
+
+    /// Adjust the saturation of this color as by [`RgbColor::saturate()`],
+    /// using `palette` to resolve [`Color256`] values.
+    ///
+    /// Returns `None` for [`Color::Default`].
+    pub fn saturate(self, amount: f64, palette: &Palette) -> Option<RgbColor> {
+        self.to_rgb(palette).map(|c| c.saturate(amount))
+    }
+
+    /// Return an iterator of `n` colors evenly spaced between `from` and `to`
+    /// as by [`RgbColor::gradient()`], using `palette` to resolve
+    /// [`Color256`] values.
+    ///
+    /// Returns `None` if either color is [`Color::Default`].
+    pub fn gradient(from: Color, to: Color, n: usize, palette: &Palette) -> Option<Gradient> {
+        Some(RgbColor::gradient(
+            from.to_rgb(palette)?,
+            to.to_rgb(palette)?,
+            n,
+        ))
+    }
+
     /// Return a new [`Style`] that uses this color as the foreground color
     pub fn as_foreground(self) -> Style {
         Style::new().foreground(Some(self))
@@ -32,6 +106,17 @@ impl Color {
             .foreground(Some(self))
             .background(Some(bg.into()))
     }
+
+    /// Parse a color string without allocating
+    pub(crate) fn parse_str(s: &str) -> Option<Color> {
+        if s.eq_ignore_ascii_case("default") {
+            Some(Color::Default)
+        } else if s.starts_with('#') || strip_nocase_prefix(s, "rgb(").is_some() {
+            RgbColor::parse_str(s).map(Color::from)
+        } else {
+            Color256::parse_str(s).map(Color::from)
+        }
+    }
 }
 
 impl From<Color256> for Color {
@@ -91,6 +176,94 @@ impl From<anstyle::Color> for Color {
     }
 }
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl TryFrom<Color> for colored::Color {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to a [`colored::Color`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `colored::Color`
+    fn try_from(value: Color) -> Result<colored::Color, crate::ConversionError> {
+        match value {
+            Color::Default => Err(crate::ConversionError),
+            Color::Color256(c) => Ok(c.into()),
+            Color::Rgb(c) => Ok(c.into()),
+        }
+    }
+}
+
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<colored::Color> for Color {
+    /// Convert a [`colored::Color`] to a `Color`
+    fn from(value: colored::Color) -> Color {
+        match value {
+            colored::Color::Black => Color256::BLACK.into(),
+            colored::Color::Red => Color256::RED.into(),
+            colored::Color::Green => Color256::GREEN.into(),
+            colored::Color::Yellow => Color256::YELLOW.into(),
+            colored::Color::Blue => Color256::BLUE.into(),
+            colored::Color::Magenta => Color256::MAGENTA.into(),
+            colored::Color::Cyan => Color256::CYAN.into(),
+            colored::Color::White => Color256::WHITE.into(),
+            colored::Color::BrightBlack => Color256::BRIGHT_BLACK.into(),
+            colored::Color::BrightRed => Color256::BRIGHT_RED.into(),
+            colored::Color::BrightGreen => Color256::BRIGHT_GREEN.into(),
+            colored::Color::BrightYellow => Color256::BRIGHT_YELLOW.into(),
+            colored::Color::BrightBlue => Color256::BRIGHT_BLUE.into(),
+            colored::Color::BrightMagenta => Color256::BRIGHT_MAGENTA.into(),
+            colored::Color::BrightCyan => Color256::BRIGHT_CYAN.into(),
+            colored::Color::BrightWhite => Color256::BRIGHT_WHITE.into(),
+            colored::Color::AnsiColor(index) => Color256(index).into(),
+            colored::Color::TrueColor { r, g, b } => RgbColor(r, g, b).into(),
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl TryFrom<Color> for console::Color {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to a [`console::Color`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `console::Color`
+    fn try_from(value: Color) -> Result<console::Color, crate::ConversionError> {
+        match value {
+            Color::Default => Err(crate::ConversionError),
+            Color::Color256(c) => Ok(c.into()),
+            Color::Rgb(c) => Ok(c.into()),
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl From<console::Color> for Color {
+    /// Convert a [`console::Color`] to a `Color`
+    fn from(value: console::Color) -> Color {
+        match value {
+            console::Color::Black => Color256::BLACK.into(),
+            console::Color::Red => Color256::RED.into(),
+            console::Color::Green => Color256::GREEN.into(),
+            console::Color::Yellow => Color256::YELLOW.into(),
+            console::Color::Blue => Color256::BLUE.into(),
+            console::Color::Magenta => Color256::MAGENTA.into(),
+            console::Color::Cyan => Color256::CYAN.into(),
+            console::Color::White => Color256::WHITE.into(),
+            console::Color::Color256(index) => Color256(index).into(),
+            console::Color::TrueColor(r, g, b) => RgbColor(r, g, b).into(),
+        }
+    }
+}
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 impl From<Color> for crossterm::style::Color {
@@ -133,6 +306,145 @@ impl From<crossterm::style::Color> for Color {
     }
 }
 
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<Color> for cursive_core::style::Color {
+    /// Convert a `Color` to a [`cursive_core::style::Color`]
+    ///
+    /// [`Color::Default`] is converted to
+    /// [`cursive_core::style::Color::TerminalDefault`].
+    fn from(value: Color) -> cursive_core::style::Color {
+        match value {
+            Color::Default => cursive_core::style::Color::TerminalDefault,
+            Color::Color256(c) => c.into(),
+            Color::Rgb(c) => c.into(),
+        }
+    }
+}
+
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<cursive_core::style::Color> for Color {
+    /// Convert a [`cursive_core::style::Color`] to a `Color`
+    ///
+    /// Components of [`RgbLowRes`][cursive_core::style::Color::RgbLowRes]
+    /// colors greater than 5 are treated as 5.
+    fn from(value: cursive_core::style::Color) -> Color {
+        match value {
+            cursive_core::style::Color::TerminalDefault => Color::Default,
+            cursive_core::style::Color::Dark(base) => Color256(base as u8).into(),
+            cursive_core::style::Color::Light(base) => Color256(base as u8 + 8).into(),
+            cursive_core::style::Color::Rgb(r, g, b) => RgbColor(r, g, b).into(),
+            cursive_core::style::Color::RgbLowRes(r, g, b) => {
+                Color256(16 + 36 * r.min(5) + 6 * g.min(5) + b.min(5)).into()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+impl TryFrom<Color> for epaint::Color32 {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to an opaque [`epaint::Color32`], resolving
+    /// [`Color256`] values using [`Palette::XTERM`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `epaint::Color32`
+    fn try_from(value: Color) -> Result<epaint::Color32, crate::ConversionError> {
+        value
+            .to_rgb(&Palette::XTERM)
+            .map(epaint::Color32::from)
+            .ok_or(crate::ConversionError)
+    }
+}
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+impl From<epaint::Color32> for Color {
+    /// Convert an [`epaint::Color32`] to a `Color`
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha component is discarded during conversion.
+    fn from(value: epaint::Color32) -> Color {
+        Color::Rgb(value.into())
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<Color> for nu_ansi_term::Color {
+    /// Convert a `Color` to a [`nu_ansi_term::Color`]
+    fn from(value: Color) -> nu_ansi_term::Color {
+        match value {
+            Color::Default => nu_ansi_term::Color::Default,
+            Color::Color256(c) => c.into(),
+            Color::Rgb(c) => c.into(),
+        }
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<nu_ansi_term::Color> for Color {
+    /// Convert a [`nu_ansi_term::Color`] to a `Color`
+    fn from(value: nu_ansi_term::Color) -> Color {
+        match value {
+            nu_ansi_term::Color::Default => Color::Default,
+            nu_ansi_term::Color::Black => Color256::BLACK.into(),
+            nu_ansi_term::Color::Red => Color256::RED.into(),
+            nu_ansi_term::Color::Green => Color256::GREEN.into(),
+            nu_ansi_term::Color::Yellow => Color256::YELLOW.into(),
+            nu_ansi_term::Color::Blue => Color256::BLUE.into(),
+            nu_ansi_term::Color::Purple => Color256::MAGENTA.into(),
+            nu_ansi_term::Color::Magenta => Color256::MAGENTA.into(),
+            nu_ansi_term::Color::Cyan => Color256::CYAN.into(),
+            nu_ansi_term::Color::White => Color256::WHITE.into(),
+            nu_ansi_term::Color::DarkGray => Color256::BRIGHT_BLACK.into(),
+            nu_ansi_term::Color::LightRed => Color256::BRIGHT_RED.into(),
+            nu_ansi_term::Color::LightGreen => Color256::BRIGHT_GREEN.into(),
+            nu_ansi_term::Color::LightYellow => Color256::BRIGHT_YELLOW.into(),
+            nu_ansi_term::Color::LightBlue => Color256::BRIGHT_BLUE.into(),
+            nu_ansi_term::Color::LightPurple => Color256::BRIGHT_MAGENTA.into(),
+            nu_ansi_term::Color::LightMagenta => Color256::BRIGHT_MAGENTA.into(),
+            nu_ansi_term::Color::LightCyan => Color256::BRIGHT_CYAN.into(),
+            nu_ansi_term::Color::LightGray => Color256::BRIGHT_WHITE.into(),
+            nu_ansi_term::Color::Fixed(index) => Color256(index).into(),
+            nu_ansi_term::Color::Rgb(r, g, b) => RgbColor(r, g, b).into(),
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<Color> for owo_colors::DynColors {
+    /// Convert a `Color` to an [`owo_colors::DynColors`]
+    fn from(value: Color) -> owo_colors::DynColors {
+        match value {
+            Color::Default => owo_colors::DynColors::Ansi(owo_colors::AnsiColors::Default),
+            Color::Color256(c) => c.into(),
+            Color::Rgb(c) => c.into(),
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<owo_colors::DynColors> for Color {
+    /// Convert an [`owo_colors::DynColors`] to a `Color`
+    ///
+    /// [`owo_colors::CssColors`] values are converted to RGB colors.
+    fn from(value: owo_colors::DynColors) -> Color {
+        Style::from(owo_colors::Style::new().color(value))
+            .get_foreground()
+            .unwrap_or_default()
+    }
+}
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
 impl From<Color> for ratatui_core::style::Color {
@@ -175,6 +487,46 @@ impl From<ratatui_core::style::Color> for Color {
     }
 }
 
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl TryFrom<Color> for palette::Srgb<u8> {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to a [`palette::Srgb<u8>`], resolving [`Color256`]
+    /// values using [`Palette::XTERM`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `palette::Srgb`
+    fn try_from(value: Color) -> Result<palette::Srgb<u8>, crate::ConversionError> {
+        value
+            .to_rgb(&Palette::XTERM)
+            .map(palette::Srgb::from)
+            .ok_or(crate::ConversionError)
+    }
+}
+
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl TryFrom<Color> for palette::Srgb<f32> {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to a [`palette::Srgb<f32>`], resolving [`Color256`]
+    /// values using [`Palette::XTERM`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `palette::Srgb`
+    fn try_from(value: Color) -> Result<palette::Srgb<f32>, crate::ConversionError> {
+        value
+            .to_rgb(&Palette::XTERM)
+            .map(palette::Srgb::from)
+            .ok_or(crate::ConversionError)
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -189,12 +541,153 @@ impl std::str::FromStr for Color {
     type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<Color, ParseColorError> {
-        if s.eq_ignore_ascii_case("default") {
-            Ok(Color::Default)
-        } else {
-            s.parse::<Color256>()
-                .map(Color::from)
-                .or_else(|_| s.parse::<RgbColor>().map(Color::from))
+        Color::parse_str(s).ok_or_else(|| ParseColorError(s.to_owned()))
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl TryFrom<Color> for syntect::highlighting::Color {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to an opaque [`syntect::highlighting::Color`],
+    /// resolving [`Color256`] values using [`Palette::XTERM`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `syntect::highlighting::Color`
+    fn try_from(value: Color) -> Result<syntect::highlighting::Color, crate::ConversionError> {
+        value
+            .to_rgb(&Palette::XTERM)
+            .map(syntect::highlighting::Color::from)
+            .ok_or(crate::ConversionError)
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<syntect::highlighting::Color> for Color {
+    /// Convert a [`syntect::highlighting::Color`] to a `Color`
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha component is discarded during conversion.
+    fn from(value: syntect::highlighting::Color) -> Color {
+        Color::Rgb(value.into())
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl TryFrom<Color> for termcolor::Color {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Color` to a [`termcolor::Color`]
+    ///
+    /// [`Color256`] values are converted to [`termcolor::Color::Ansi256`]
+    /// values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `value` is `Color::Default`, which is not
+    /// representable by `termcolor::Color`
+    fn try_from(value: Color) -> Result<termcolor::Color, crate::ConversionError> {
+        match value {
+            Color::Default => Err(crate::ConversionError),
+            Color::Color256(c) => Ok(c.into()),
+            Color::Rgb(c) => Ok(c.into()),
+        }
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<termcolor::Color> for Color {
+    /// Convert a [`termcolor::Color`] to a `Color`
+    ///
+    /// Named colors are converted to colors 0 through 7.  Use the conversion
+    /// from [`termcolor::ColorSpec`] to [`Style`] in order to take the
+    /// "intense" flag into account.
+    fn from(value: termcolor::Color) -> Color {
+        match value {
+            termcolor::Color::Black => Color256::BLACK.into(),
+            termcolor::Color::Red => Color256::RED.into(),
+            termcolor::Color::Green => Color256::GREEN.into(),
+            termcolor::Color::Yellow => Color256::YELLOW.into(),
+            termcolor::Color::Blue => Color256::BLUE.into(),
+            termcolor::Color::Magenta => Color256::MAGENTA.into(),
+            termcolor::Color::Cyan => Color256::CYAN.into(),
+            termcolor::Color::White => Color256::WHITE.into(),
+            termcolor::Color::Ansi256(index) => Color256(index).into(),
+            termcolor::Color::Rgb(r, g, b) => RgbColor(r, g, b).into(),
+            _ => Color::Default, // non-exhaustive
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+impl termion::color::Color for Color {
+    /// Write the escape sequence for setting the foreground color to this
+    /// color.  [`Color::Default`] is written as [`termion::color::Reset`].
+    fn write_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Default => termion::color::Color::write_fg(&termion::color::Reset, f),
+            Color::Color256(c) => termion::color::Color::write_fg(c, f),
+            Color::Rgb(c) => termion::color::Color::write_fg(c, f),
+        }
+    }
+
+    /// Write the escape sequence for setting the background color to this
+    /// color.  [`Color::Default`] is written as [`termion::color::Reset`].
+    fn write_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Default => termion::color::Color::write_bg(&termion::color::Reset, f),
+            Color::Color256(c) => termion::color::Color::write_bg(c, f),
+            Color::Rgb(c) => termion::color::Color::write_bg(c, f),
+        }
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<Color> for yansi::Color {
+    /// Convert a `Color` to a [`yansi::Color`]
+    fn from(value: Color) -> yansi::Color {
+        match value {
+            Color::Default => yansi::Color::Primary,
+            Color::Color256(c) => c.into(),
+            Color::Rgb(c) => c.into(),
+        }
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<yansi::Color> for Color {
+    /// Convert a [`yansi::Color`] to a `Color`
+    fn from(value: yansi::Color) -> Color {
+        match value {
+            yansi::Color::Primary => Color::Default,
+            yansi::Color::Black => Color256::BLACK.into(),
+            yansi::Color::Red => Color256::RED.into(),
+            yansi::Color::Green => Color256::GREEN.into(),
+            yansi::Color::Yellow => Color256::YELLOW.into(),
+            yansi::Color::Blue => Color256::BLUE.into(),
+            yansi::Color::Magenta => Color256::MAGENTA.into(),
+            yansi::Color::Cyan => Color256::CYAN.into(),
+            yansi::Color::White => Color256::WHITE.into(),
+            yansi::Color::BrightBlack => Color256::BRIGHT_BLACK.into(),
+            yansi::Color::BrightRed => Color256::BRIGHT_RED.into(),
+            yansi::Color::BrightGreen => Color256::BRIGHT_GREEN.into(),
+            yansi::Color::BrightYellow => Color256::BRIGHT_YELLOW.into(),
+            yansi::Color::BrightBlue => Color256::BRIGHT_BLUE.into(),
+            yansi::Color::BrightMagenta => Color256::BRIGHT_MAGENTA.into(),
+            yansi::Color::BrightCyan => Color256::BRIGHT_CYAN.into(),
+            yansi::Color::BrightWhite => Color256::BRIGHT_WHITE.into(),
+            yansi::Color::Fixed(index) => Color256(index).into(),
+            yansi::Color::Rgb(r, g, b) => RgbColor(r, g, b).into(),
         }
     }
 }
@@ -274,6 +767,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_rgb() {
+        let palette = Palette::XTERM;
+        assert_eq!(Color::Default.to_rgb(&palette), None);
+        assert_eq!(
+            Color::from(Color256::YELLOW).to_rgb(&palette),
+            Some(RgbColor(0x80, 0x80, 0x00))
+        );
+        assert_eq!(
+            Color::from((1, 2, 3)).to_rgb(&palette),
+            Some(RgbColor(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn test_manipulation() {
+        let palette = Palette::XTERM;
+        let grey = Color::from(Color256::BRIGHT_BLACK);
+        assert_eq!(
+            grey.lighten(0.5, &palette),
+            Some(RgbColor(0xC0, 0xC0, 0xC0))
+        );
+        assert_eq!(grey.darken(0.5, &palette), Some(RgbColor(0x40, 0x40, 0x40)));
+        assert_eq!(
+            grey.saturate(1.0, &palette),
+            Some(RgbColor(0x80, 0x80, 0x80))
+        );
+        assert_eq!(Color::Default.lighten(0.5, &palette), None);
+        assert_eq!(Color::Default.darken(0.5, &palette), None);
+        assert_eq!(Color::Default.saturate(0.5, &palette), None);
+    }
+
+    #[test]
+    fn test_gradient() {
+        let palette = Palette::XTERM;
+        let colors = Color::gradient(
+            Color256::BRIGHT_BLACK.into(),
+            RgbColor(0, 0, 0).into(),
+            3,
+            &palette,
+        )
+        .unwrap()
+        .collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            [
+                RgbColor(0x80, 0x80, 0x80),
+                RgbColor(0x40, 0x40, 0x40),
+                RgbColor(0, 0, 0)
+            ]
+        );
+        assert!(Color::gradient(Color::Default, Color256::RED.into(), 3, &palette).is_none());
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_try_into_palette() {
+        assert_eq!(
+            palette::Srgb::<u8>::try_from(Color::from(Color256::BRIGHT_BLUE)),
+            Ok(palette::Srgb::new(0, 0, 255))
+        );
+        assert_eq!(
+            palette::Srgb::<u8>::try_from(Color::from((1, 2, 3))),
+            Ok(palette::Srgb::new(1, 2, 3))
+        );
+        assert_eq!(
+            palette::Srgb::<u8>::try_from(Color::Default),
+            Err(crate::ConversionError)
+        );
+        assert!(palette::Srgb::<f32>::try_from(Color::Default).is_err());
+    }
+
     #[test]
     fn test_parse_err() {
         assert!("mauve".parse::<Color>().is_err());
@@ -379,6 +379,18 @@ impl Color256 {
             .foreground(Some(self.into()))
             .background(Some(bg.into()))
     }
+
+    /// Parse a color name or `color(N)` string without allocating
+    pub(crate) fn parse_str(s: &str) -> Option<Color256> {
+        if let Some(index) = strip_nocase_prefix(s, "color(") {
+            index
+                .strip_suffix(')')
+                .and_then(|s| s.parse::<u8>().ok())
+                .map(Color256)
+        } else {
+            BY_NAME.get(&UniCase::ascii(s)).copied()
+        }
+    }
 }
 
 impl From<u8> for Color256 {
@@ -420,6 +432,58 @@ impl From<anstyle::Ansi256Color> for Color256 {
     }
 }
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<Color256> for colored::Color {
+    /// Convert a `Color256` to a [`colored::Color`]
+    ///
+    /// Colors 0 through 15 are converted to the corresponding named colors;
+    /// all other colors are converted to [`colored::Color::AnsiColor`] values.
+    fn from(value: Color256) -> colored::Color {
+        match value.0 {
+            0 => colored::Color::Black,
+            1 => colored::Color::Red,
+            2 => colored::Color::Green,
+            3 => colored::Color::Yellow,
+            4 => colored::Color::Blue,
+            5 => colored::Color::Magenta,
+            6 => colored::Color::Cyan,
+            7 => colored::Color::White,
+            8 => colored::Color::BrightBlack,
+            9 => colored::Color::BrightRed,
+            10 => colored::Color::BrightGreen,
+            11 => colored::Color::BrightYellow,
+            12 => colored::Color::BrightBlue,
+            13 => colored::Color::BrightMagenta,
+            14 => colored::Color::BrightCyan,
+            15 => colored::Color::BrightWhite,
+            i => colored::Color::AnsiColor(i),
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl From<Color256> for console::Color {
+    /// Convert a `Color256` to a [`console::Color`]
+    ///
+    /// Colors 0 through 7 are converted to the corresponding named colors;
+    /// all other colors are converted to [`console::Color::Color256`] values.
+    fn from(value: Color256) -> console::Color {
+        match value.0 {
+            0 => console::Color::Black,
+            1 => console::Color::Red,
+            2 => console::Color::Green,
+            3 => console::Color::Yellow,
+            4 => console::Color::Blue,
+            5 => console::Color::Magenta,
+            6 => console::Color::Cyan,
+            7 => console::Color::White,
+            i => console::Color::Color256(i),
+        }
+    }
+}
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 impl From<Color256> for crossterm::style::Color {
@@ -429,6 +493,86 @@ impl From<Color256> for crossterm::style::Color {
     }
 }
 
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<Color256> for cursive_core::style::Color {
+    /// Convert a `Color256` to a [`cursive_core::style::Color`]
+    ///
+    /// Colors 0 through 7 are converted to [`Dark`][cursive_core::style::Color::Dark]
+    /// colors, colors 8 through 15 are converted to
+    /// [`Light`][cursive_core::style::Color::Light] colors, colors 16 through
+    /// 231 are converted to [`RgbLowRes`][cursive_core::style::Color::RgbLowRes]
+    /// colors, and the grayscale colors are converted to
+    /// [`Rgb`][cursive_core::style::Color::Rgb] colors.
+    fn from(value: Color256) -> cursive_core::style::Color {
+        cursive_core::style::Color::from_256colors(value.0)
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<Color256> for nu_ansi_term::Color {
+    /// Convert a `Color256` to a [`nu_ansi_term::Color`]
+    ///
+    /// Colors 0 through 15 are converted to the corresponding named colors;
+    /// all other colors are converted to [`nu_ansi_term::Color::Fixed`]
+    /// values.
+    fn from(value: Color256) -> nu_ansi_term::Color {
+        match value.0 {
+            0 => nu_ansi_term::Color::Black,
+            1 => nu_ansi_term::Color::Red,
+            2 => nu_ansi_term::Color::Green,
+            3 => nu_ansi_term::Color::Yellow,
+            4 => nu_ansi_term::Color::Blue,
+            5 => nu_ansi_term::Color::Magenta,
+            6 => nu_ansi_term::Color::Cyan,
+            7 => nu_ansi_term::Color::White,
+            8 => nu_ansi_term::Color::DarkGray,
+            9 => nu_ansi_term::Color::LightRed,
+            10 => nu_ansi_term::Color::LightGreen,
+            11 => nu_ansi_term::Color::LightYellow,
+            12 => nu_ansi_term::Color::LightBlue,
+            13 => nu_ansi_term::Color::LightMagenta,
+            14 => nu_ansi_term::Color::LightCyan,
+            15 => nu_ansi_term::Color::LightGray,
+            i => nu_ansi_term::Color::Fixed(i),
+        }
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<Color256> for owo_colors::DynColors {
+    /// Convert a `Color256` to an [`owo_colors::DynColors`]
+    ///
+    /// Colors 0 through 15 are converted to [`owo_colors::AnsiColors`]
+    /// values; all other colors are converted to
+    /// [`owo_colors::XtermColors`] values.
+    fn from(value: Color256) -> owo_colors::DynColors {
+        use owo_colors::AnsiColors;
+        let ansi = match value.0 {
+            0 => AnsiColors::Black,
+            1 => AnsiColors::Red,
+            2 => AnsiColors::Green,
+            3 => AnsiColors::Yellow,
+            4 => AnsiColors::Blue,
+            5 => AnsiColors::Magenta,
+            6 => AnsiColors::Cyan,
+            7 => AnsiColors::White,
+            8 => AnsiColors::BrightBlack,
+            9 => AnsiColors::BrightRed,
+            10 => AnsiColors::BrightGreen,
+            11 => AnsiColors::BrightYellow,
+            12 => AnsiColors::BrightBlue,
+            13 => AnsiColors::BrightMagenta,
+            14 => AnsiColors::BrightCyan,
+            15 => AnsiColors::BrightWhite,
+            i => return owo_colors::DynColors::Xterm(i.into()),
+        };
+        owo_colors::DynColors::Ansi(ansi)
+    }
+}
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
 impl From<Color256> for ratatui_core::style::Color {
@@ -438,6 +582,75 @@ impl From<Color256> for ratatui_core::style::Color {
     }
 }
 
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<Color256> for termcolor::Color {
+    /// Convert a `Color256` to a [`termcolor::Color`]
+    ///
+    /// All colors are converted to [`termcolor::Color::Ansi256`] values.  Use
+    /// the conversion from [`Style`] to
+    /// [`termcolor::ColorSpec`] in order to convert colors 0 through 15 to
+    /// named colors.
+    fn from(value: Color256) -> termcolor::Color {
+        termcolor::Color::Ansi256(value.0)
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+impl From<Color256> for termion::color::AnsiValue {
+    /// Convert a `Color256` to a [`termion::color::AnsiValue`]
+    fn from(value: Color256) -> termion::color::AnsiValue {
+        termion::color::AnsiValue(value.0)
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+impl termion::color::Color for Color256 {
+    /// Write the escape sequence for setting the foreground color to this
+    /// color
+    fn write_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        termion::color::Color::write_fg(&termion::color::AnsiValue::from(*self), f)
+    }
+
+    /// Write the escape sequence for setting the background color to this
+    /// color
+    fn write_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        termion::color::Color::write_bg(&termion::color::AnsiValue::from(*self), f)
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<Color256> for yansi::Color {
+    /// Convert a `Color256` to a [`yansi::Color`]
+    ///
+    /// Colors 0 through 15 are converted to the corresponding named colors;
+    /// all other colors are converted to [`yansi::Color::Fixed`] values.
+    fn from(value: Color256) -> yansi::Color {
+        match value.0 {
+            0 => yansi::Color::Black,
+            1 => yansi::Color::Red,
+            2 => yansi::Color::Green,
+            3 => yansi::Color::Yellow,
+            4 => yansi::Color::Blue,
+            5 => yansi::Color::Magenta,
+            6 => yansi::Color::Cyan,
+            7 => yansi::Color::White,
+            8 => yansi::Color::BrightBlack,
+            9 => yansi::Color::BrightRed,
+            10 => yansi::Color::BrightGreen,
+            11 => yansi::Color::BrightYellow,
+            12 => yansi::Color::BrightBlue,
+            13 => yansi::Color::BrightMagenta,
+            14 => yansi::Color::BrightCyan,
+            15 => yansi::Color::BrightWhite,
+            i => yansi::Color::Fixed(i),
+        }
+    }
+}
+
 impl fmt::Display for Color256 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.name() {
@@ -451,16 +664,7 @@ impl std::str::FromStr for Color256 {
     type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<Color256, ParseColorError> {
-        if let Some(color) = BY_NAME.get(&UniCase::ascii(s)).copied() {
-            Ok(color)
-        } else if let Some(index) = strip_nocase_prefix(s, "color(")
-            .and_then(|s| s.strip_suffix(')'))
-            .and_then(|s| s.parse::<u8>().ok())
-        {
-            Ok(Color256(index))
-        } else {
-            Err(ParseColorError(s.to_owned()))
-        }
+        Color256::parse_str(s).ok_or_else(|| ParseColorError(s.to_owned()))
     }
 }
 
@@ -0,0 +1,471 @@
+use crate::rgbcolor::RgbColor;
+use crate::util::component_from_f64;
+
+/// A color in the HSL (hue, saturation, lightness) color space
+///
+/// An `Hsl` value can be converted to & from an [`RgbColor`] via the `From`
+/// trait.  Converting an `RgbColor` to `Hsl` and back always produces the
+/// original color.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Hsl, RgbColor};
+///
+/// let hsl = Hsl::from(RgbColor(255, 128, 0));
+/// assert!((hsl.hue - 30.1).abs() < 0.1);
+/// assert_eq!(RgbColor::from(hsl), RgbColor(255, 128, 0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsl {
+    /// The hue, in degrees from 0.0 (inclusive) to 360.0 (exclusive)
+    pub hue: f64,
+    /// The saturation, from 0.0 to 1.0
+    pub saturation: f64,
+    /// The lightness, from 0.0 (black) to 1.0 (white)
+    pub lightness: f64,
+}
+
+/// A color in the HSV (hue, saturation, value) color space
+///
+/// An `Hsv` value can be converted to & from an [`RgbColor`] via the `From`
+/// trait.  Converting an `RgbColor` to `Hsv` and back always produces the
+/// original color.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsv {
+    /// The hue, in degrees from 0.0 (inclusive) to 360.0 (exclusive)
+    pub hue: f64,
+    /// The saturation, from 0.0 to 1.0
+    pub saturation: f64,
+    /// The value, from 0.0 (black) to 1.0
+    pub value: f64,
+}
+
+/// A color in the CIE L\*a\*b\* color space, relative to the D65 white point
+///
+/// A `Lab` value can be converted to & from an [`RgbColor`] via the `From`
+/// trait.  Converting an `RgbColor` to `Lab` and back always produces the
+/// original color; converting a `Lab` value outside the sRGB gamut to
+/// `RgbColor` clamps each component.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lab {
+    /// The perceptual lightness, from 0.0 (black) to 100.0 (white)
+    pub lightness: f64,
+    /// The green–red axis
+    pub a: f64,
+    /// The blue–yellow axis
+    pub b: f64,
+}
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color
+/// space
+///
+/// An `Oklab` value can be converted to & from an [`RgbColor`] or an
+/// [`Oklch`] via the `From` trait.  Converting an `RgbColor` to `Oklab` and
+/// back always produces the original color; converting an `Oklab` value
+/// outside the sRGB gamut to `RgbColor` clamps each component.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// The perceptual lightness, from 0.0 (black) to 1.0 (white)
+    pub lightness: f64,
+    /// The green–red axis
+    pub a: f64,
+    /// The blue–yellow axis
+    pub b: f64,
+}
+
+/// A color in the Oklch color space, the polar form of [`Oklab`]
+///
+/// An `Oklch` value can be converted to & from an [`RgbColor`] or an
+/// [`Oklab`] via the `From` trait.  Converting an `RgbColor` to `Oklch` and
+/// back always produces the original color; converting an `Oklch` value
+/// outside the sRGB gamut to `RgbColor` clamps each component.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// The perceptual lightness, from 0.0 (black) to 1.0 (white)
+    pub lightness: f64,
+    /// The chroma, from 0.0 (grey) upwards
+    pub chroma: f64,
+    /// The hue, in degrees from 0.0 (inclusive) to 360.0 (exclusive)
+    pub hue: f64,
+}
+
+impl From<RgbColor> for Hsl {
+    fn from(value: RgbColor) -> Hsl {
+        let (hue, max, min) = hue_max_min(value);
+        let lightness = f64::midpoint(scale(max), scale(min));
+        let saturation = if max == min {
+            0.0
+        } else {
+            scale(max - min) / (1.0 - 2.0f64.mul_add(lightness, -1.0).abs())
+        };
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl From<Hsl> for RgbColor {
+    fn from(value: Hsl) -> RgbColor {
+        let lightness = value.lightness.clamp(0.0, 1.0);
+        let chroma =
+            (1.0 - 2.0f64.mul_add(lightness, -1.0).abs()) * value.saturation.clamp(0.0, 1.0);
+        from_hue_chroma(value.hue, chroma, lightness - chroma / 2.0)
+    }
+}
+
+impl From<RgbColor> for Hsv {
+    fn from(value: RgbColor) -> Hsv {
+        let (hue, max, min) = hue_max_min(value);
+        let saturation = if max == 0 {
+            0.0
+        } else {
+            f64::from(max - min) / f64::from(max)
+        };
+        Hsv {
+            hue,
+            saturation,
+            value: scale(max),
+        }
+    }
+}
+
+impl From<Hsv> for RgbColor {
+    fn from(value: Hsv) -> RgbColor {
+        let v = value.value.clamp(0.0, 1.0);
+        let chroma = v * value.saturation.clamp(0.0, 1.0);
+        from_hue_chroma(value.hue, chroma, v - chroma)
+    }
+}
+
+/// The D65 reference white in CIE XYZ
+const D65: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175_0],
+    [0.019_333_9, 0.119_192_0, 0.950_304_1],
+];
+
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266_0, 1.876_010_8, 0.041_556_0],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+impl From<RgbColor> for Lab {
+    fn from(value: RgbColor) -> Lab {
+        fn f(t: f64) -> f64 {
+            if t > LAB_EPSILON {
+                t.cbrt()
+            } else {
+                t / (3.0 * LAB_DELTA * LAB_DELTA) + 4.0 / 29.0
+            }
+        }
+
+        let xyz = mat_mul(&SRGB_TO_XYZ, to_linear_rgb(value));
+        let fx = f(xyz[0] / D65[0]);
+        let fy = f(xyz[1] / D65[1]);
+        let fz = f(xyz[2] / D65[2]);
+        Lab {
+            lightness: 116.0f64.mul_add(fy, -16.0),
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl From<Lab> for RgbColor {
+    fn from(value: Lab) -> RgbColor {
+        fn f_inv(t: f64) -> f64 {
+            if t > LAB_DELTA {
+                t * t * t
+            } else {
+                3.0 * LAB_DELTA * LAB_DELTA * (t - 4.0 / 29.0)
+            }
+        }
+
+        let fy = (value.lightness + 16.0) / 116.0;
+        let fx = fy + value.a / 500.0;
+        let fz = fy - value.b / 200.0;
+        let xyz = [D65[0] * f_inv(fx), D65[1] * f_inv(fy), D65[2] * f_inv(fz)];
+        from_linear_rgb(mat_mul(&XYZ_TO_SRGB, xyz))
+    }
+}
+
+const LAB_DELTA: f64 = 6.0 / 29.0;
+const LAB_EPSILON: f64 = LAB_DELTA * LAB_DELTA * LAB_DELTA;
+
+const SRGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+    [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+    [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.210_454_255_3, 0.793_617_785_0, -0.004_072_046_8],
+    [1.977_998_495_1, -2.428_592_205_0, 0.450_593_709_9],
+    [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766_0],
+];
+
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.396_337_777_4, 0.215_803_757_3],
+    [1.0, -0.105_561_345_8, -0.063_854_172_8],
+    [1.0, -0.089_484_177_5, -1.291_485_548_0],
+];
+
+const LMS_TO_SRGB: [[f64; 3]; 3] = [
+    [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+    [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+    [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701_0],
+];
+
+impl From<RgbColor> for Oklab {
+    fn from(value: RgbColor) -> Oklab {
+        let lms = mat_mul(&SRGB_TO_LMS, to_linear_rgb(value)).map(f64::cbrt);
+        let [lightness, a, b] = mat_mul(&LMS_TO_OKLAB, lms);
+        Oklab { lightness, a, b }
+    }
+}
+
+impl From<Oklab> for RgbColor {
+    fn from(value: Oklab) -> RgbColor {
+        let lms = mat_mul(&OKLAB_TO_LMS, [value.lightness, value.a, value.b]).map(|c| c * c * c);
+        from_linear_rgb(mat_mul(&LMS_TO_SRGB, lms))
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(value: Oklab) -> Oklch {
+        Oklch {
+            lightness: value.lightness,
+            chroma: value.a.hypot(value.b),
+            hue: value.b.atan2(value.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(value: Oklch) -> Oklab {
+        let (sin, cos) = value.hue.to_radians().sin_cos();
+        Oklab {
+            lightness: value.lightness,
+            a: value.chroma * cos,
+            b: value.chroma * sin,
+        }
+    }
+}
+
+impl From<RgbColor> for Oklch {
+    fn from(value: RgbColor) -> Oklch {
+        Oklch::from(Oklab::from(value))
+    }
+}
+
+impl From<Oklch> for RgbColor {
+    fn from(value: Oklch) -> RgbColor {
+        RgbColor::from(Oklab::from(value))
+    }
+}
+
+/// Convert an sRGB component to linear light, from 0.0 to 1.0
+pub(crate) fn srgb_to_linear(c: u8) -> f64 {
+    let c = f64::from(c) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear-light value to an sRGB component, clamping out-of-gamut
+/// values
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055f64.mul_add(c.powf(1.0 / 2.4), -0.055)
+    };
+    component_from_f64(c * 255.0)
+}
+
+fn to_linear_rgb(color: RgbColor) -> [f64; 3] {
+    [
+        srgb_to_linear(color.0),
+        srgb_to_linear(color.1),
+        srgb_to_linear(color.2),
+    ]
+}
+
+fn from_linear_rgb([r, g, b]: [f64; 3]) -> RgbColor {
+    RgbColor(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+fn mat_mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    m.map(|[x, y, z]| x.mul_add(v[0], y.mul_add(v[1], z * v[2])))
+}
+
+/// Scale a color component to the range 0.0 to 1.0
+fn scale(c: u8) -> f64 {
+    f64::from(c) / 255.0
+}
+
+/// Compute the hue (in degrees) of an RGB color along with its maximum &
+/// minimum components
+fn hue_max_min(color: RgbColor) -> (f64, u8, u8) {
+    let RgbColor(r, g, b) = color;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = f64::from(max - min);
+    let (rf, gf, bf) = (f64::from(r), f64::from(g), f64::from(b));
+    let hue = if max == min {
+        0.0
+    } else if max == r {
+        60.0 * ((gf - bf) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((bf - rf) / delta + 2.0)
+    } else {
+        60.0 * ((rf - gf) / delta + 4.0)
+    };
+    (hue, max, min)
+}
+
+/// Construct an RGB color from a hue (in degrees), a chroma, and an amount to
+/// add to each component, all but the hue in the range 0.0 to 1.0
+fn from_hue_chroma(hue: f64, chroma: f64, m: f64) -> RgbColor {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h {
+        h if h < 1.0 => (chroma, x, 0.0),
+        h if h < 2.0 => (x, chroma, 0.0),
+        h if h < 3.0 => (0.0, chroma, x),
+        h if h < 4.0 => (0.0, x, chroma),
+        h if h < 5.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    RgbColor(
+        component_from_f64((r + m) * 255.0),
+        component_from_f64((g + m) * 255.0),
+        component_from_f64((b + m) * 255.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn sample_colors() -> impl Iterator<Item = RgbColor> {
+        (0..=255u8).step_by(15).flat_map(|r| {
+            (0..=255u8)
+                .step_by(17)
+                .flat_map(move |g| (0..=255u8).step_by(5).map(move |b| RgbColor(r, g, b)))
+        })
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), 0.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 255, 255), 0.0, 0.0, 1.0)]
+    #[case(RgbColor(255, 0, 0), 0.0, 1.0, 0.5)]
+    #[case(RgbColor(0, 255, 0), 120.0, 1.0, 0.5)]
+    #[case(RgbColor(0, 0, 255), 240.0, 1.0, 0.5)]
+    #[case(RgbColor(255, 0, 255), 300.0, 1.0, 0.5)]
+    #[case(RgbColor(0x80, 0x80, 0x80), 0.0, 0.0, 0.501_961)]
+    #[case(RgbColor(0x33, 0x66, 0x99), 210.0, 0.5, 0.4)]
+    fn hsl(#[case] rgb: RgbColor, #[case] h: f64, #[case] s: f64, #[case] l: f64) {
+        let hsl = Hsl::from(rgb);
+        assert_close(hsl.hue, h, 1e-6);
+        assert_close(hsl.saturation, s, 1e-6);
+        assert_close(hsl.lightness, l, 1e-6);
+    }
+
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), 0.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 255, 255), 0.0, 0.0, 1.0)]
+    #[case(RgbColor(255, 0, 0), 0.0, 1.0, 1.0)]
+    #[case(RgbColor(0, 128, 0), 120.0, 1.0, 0.501_961)]
+    #[case(RgbColor(0x33, 0x66, 0x99), 210.0, 0.666_667, 0.6)]
+    fn hsv(#[case] rgb: RgbColor, #[case] h: f64, #[case] s: f64, #[case] v: f64) {
+        let hsv = Hsv::from(rgb);
+        assert_close(hsv.hue, h, 1e-6);
+        assert_close(hsv.saturation, s, 1e-6);
+        assert_close(hsv.value, v, 1e-6);
+    }
+
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), 0.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 255, 255), 100.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 0, 0), 53.2408, 80.0925, 67.2032)]
+    #[case(RgbColor(0, 0, 255), 32.2970, 79.1875, -107.8602)]
+    fn lab(#[case] rgb: RgbColor, #[case] l: f64, #[case] a: f64, #[case] b: f64) {
+        let lab = Lab::from(rgb);
+        assert_close(lab.lightness, l, 1e-3);
+        assert_close(lab.a, a, 1e-3);
+        assert_close(lab.b, b, 1e-3);
+    }
+
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), 0.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 255, 255), 1.0, 0.0, 0.0)]
+    #[case(RgbColor(255, 0, 0), 0.627_955, 0.224_863, 0.125_846)]
+    #[case(RgbColor(0, 0, 255), 0.452_014, -0.032_457, -0.311_528)]
+    fn oklab(#[case] rgb: RgbColor, #[case] l: f64, #[case] a: f64, #[case] b: f64) {
+        let oklab = Oklab::from(rgb);
+        assert_close(oklab.lightness, l, 1e-4);
+        assert_close(oklab.a, a, 1e-4);
+        assert_close(oklab.b, b, 1e-4);
+    }
+
+    #[test]
+    fn oklch() {
+        let oklch = Oklch::from(RgbColor(255, 0, 0));
+        assert_close(oklch.lightness, 0.627_955, 1e-4);
+        assert_close(oklch.chroma, 0.257_683, 1e-4);
+        assert_close(oklch.hue, 29.2339, 1e-2);
+        let oklch = Oklch::from(RgbColor(0, 0, 255));
+        assert_close(oklch.hue, 264.052, 1e-2);
+    }
+
+    #[test]
+    fn round_trips() {
+        for color in sample_colors() {
+            assert_eq!(RgbColor::from(Hsl::from(color)), color);
+            assert_eq!(RgbColor::from(Hsv::from(color)), color);
+            assert_eq!(RgbColor::from(Lab::from(color)), color);
+            assert_eq!(RgbColor::from(Oklab::from(color)), color);
+            assert_eq!(RgbColor::from(Oklch::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn out_of_gamut_clamps() {
+        let lab = Lab {
+            lightness: 50.0,
+            a: 200.0,
+            b: 0.0,
+        };
+        assert_eq!(RgbColor::from(lab).1, 0);
+        let oklch = Oklch {
+            lightness: 1.5,
+            chroma: 0.0,
+            hue: 0.0,
+        };
+        assert_eq!(RgbColor::from(oklch), RgbColor(255, 255, 255));
+        let hsl = Hsl {
+            hue: -120.0,
+            saturation: 2.0,
+            lightness: 0.5,
+        };
+        assert_eq!(RgbColor::from(hsl), RgbColor(0, 0, 255));
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Levels of text readability as defined by the [WCAG 2.x contrast
+/// requirements][wcag]
+///
+/// `Readability` values are ordered from least to most readable, so they can
+/// be compared against a minimum acceptable level.
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+///
+/// # Example
+///
+/// ```
+/// use parse_style::Readability;
+///
+/// assert_eq!(Readability::from_contrast_ratio(5.0), Readability::Aa);
+/// assert!(Readability::from_contrast_ratio(2.5) < Readability::AaLarge);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Readability {
+    /// Contrast ratio below 3:1; insufficient for any text
+    Insufficient,
+    /// Contrast ratio of at least 3:1; meets level AA for large text only
+    AaLarge,
+    /// Contrast ratio of at least 4.5:1; meets level AA for all text and
+    /// level AAA for large text
+    Aa,
+    /// Contrast ratio of at least 7:1; meets level AAA for all text
+    Aaa,
+}
+
+impl Readability {
+    /// Return the readability level achieved by the given contrast ratio
+    pub fn from_contrast_ratio(ratio: f64) -> Readability {
+        if ratio >= 7.0 {
+            Readability::Aaa
+        } else if ratio >= 4.5 {
+            Readability::Aa
+        } else if ratio >= 3.0 {
+            Readability::AaLarge
+        } else {
+            Readability::Insufficient
+        }
+    }
+
+    /// Return the minimum contrast ratio required to achieve this level
+    pub fn min_contrast_ratio(self) -> f64 {
+        match self {
+            Readability::Insufficient => 1.0,
+            Readability::AaLarge => 3.0,
+            Readability::Aa => 4.5,
+            Readability::Aaa => 7.0,
+        }
+    }
+}
+
+impl fmt::Display for Readability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Readability::Insufficient => write!(f, "insufficient"),
+            Readability::AaLarge => write!(f, "AA (large text)"),
+            Readability::Aa => write!(f, "AA"),
+            Readability::Aaa => write!(f, "AAA"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1.0, Readability::Insufficient)]
+    #[case(2.99, Readability::Insufficient)]
+    #[case(3.0, Readability::AaLarge)]
+    #[case(4.49, Readability::AaLarge)]
+    #[case(4.5, Readability::Aa)]
+    #[case(6.99, Readability::Aa)]
+    #[case(7.0, Readability::Aaa)]
+    #[case(21.0, Readability::Aaa)]
+    fn from_contrast_ratio(#[case] ratio: f64, #[case] level: Readability) {
+        assert_eq!(Readability::from_contrast_ratio(ratio), level);
+    }
+
+    #[test]
+    fn min_contrast_ratio_round_trip() {
+        for level in [
+            Readability::Insufficient,
+            Readability::AaLarge,
+            Readability::Aa,
+            Readability::Aaa,
+        ] {
+            assert_eq!(
+                Readability::from_contrast_ratio(level.min_contrast_ratio()),
+                level
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Converting styles to [`egui`](https://crates.io/crates/egui) text formats
+use crate::attributes::Attribute;
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::style::Style;
+use epaint::text::TextFormat;
+use epaint::{Color32, Stroke};
+
+/// Apply `style` to the [`TextFormat`] `base`, resolving
+/// [`Color256`][crate::Color256] values using `palette`.
+///
+/// The foreground and background colors of the style replace those of `base`
+/// unless they are unset or [`Color::Default`].  [`Attribute::Italic`] sets
+/// the format's `italics` flag, while [`Attribute::Underline`] and
+/// [`Attribute::Strike`] draw a one-point line in the text color; disabling
+/// any of these attributes clears the corresponding property of `base`.  All
+/// other properties are taken from `base` unchanged.
+///
+/// # Data Loss
+///
+/// [`Attribute::Underline2`] is converted to a single underline.
+///
+/// The following attributes are discarded during conversion, as
+/// `TextFormat` has no equivalent properties:
+///
+/// - [`Attribute::Bold`]
+/// - [`Attribute::Dim`]
+/// - [`Attribute::Blink`]
+/// - [`Attribute::Blink2`]
+/// - [`Attribute::Reverse`]
+/// - [`Attribute::Conceal`]
+/// - [`Attribute::Frame`]
+/// - [`Attribute::Encircle`]
+/// - [`Attribute::Overline`]
+///
+/// # Example
+///
+/// ```
+/// use epaint::{Color32, text::TextFormat};
+/// use parse_style::{Palette, Style, egui::text_format};
+///
+/// let style = "italic red on default".parse::<Style>().unwrap();
+/// let base = TextFormat {
+///     background: Color32::BLACK,
+///     ..TextFormat::default()
+/// };
+/// let fmt = text_format(style, &Palette::XTERM, base);
+/// assert_eq!(fmt.color, Color32::from_rgb(0x80, 0, 0));
+/// assert_eq!(fmt.background, Color32::BLACK);
+/// assert!(fmt.italics);
+/// ```
+pub fn text_format(style: Style, palette: &Palette, base: TextFormat) -> TextFormat {
+    let resolve = |c: Option<Color>, default: Color32| {
+        c.and_then(|c| c.to_rgb(palette))
+            .map_or(default, Color32::from)
+    };
+    let color = resolve(style.get_foreground(), base.color);
+    let background = resolve(style.get_background(), base.background);
+    let italics = if style.is_enabled(Attribute::Italic) {
+        true
+    } else if style.is_disabled(Attribute::Italic) {
+        false
+    } else {
+        base.italics
+    };
+    let line = |enabled: bool, disabled: bool, default: Stroke| {
+        if enabled {
+            Stroke::new(1.0, color)
+        } else if disabled {
+            Stroke::NONE
+        } else {
+            default
+        }
+    };
+    let underline = line(
+        style.is_enabled(Attribute::Underline) || style.is_enabled(Attribute::Underline2),
+        style.is_disabled(Attribute::Underline) || style.is_disabled(Attribute::Underline2),
+        base.underline,
+    );
+    let strikethrough = line(
+        style.is_enabled(Attribute::Strike),
+        style.is_disabled(Attribute::Strike),
+        base.strikethrough,
+    );
+    TextFormat {
+        color,
+        background,
+        italics,
+        underline,
+        strikethrough,
+        ..base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rgbcolor::RgbColor;
+
+    #[test]
+    fn empty() {
+        let base = TextFormat {
+            color: Color32::WHITE,
+            italics: true,
+            underline: Stroke::new(2.0, Color32::RED),
+            ..TextFormat::default()
+        };
+        let fmt = text_format(Style::new(), &Palette::XTERM, base.clone());
+        assert_eq!(fmt, base);
+    }
+
+    #[test]
+    fn lines() {
+        let style = "blue underline2 strike".parse::<Style>().unwrap();
+        let fmt = text_format(style, &Palette::XTERM, TextFormat::default());
+        let blue = Color32::from_rgb(0, 0, 0x80);
+        assert_eq!(fmt.color, blue);
+        assert_eq!(fmt.underline, Stroke::new(1.0, blue));
+        assert_eq!(fmt.strikethrough, Stroke::new(1.0, blue));
+        assert!(!fmt.italics);
+    }
+
+    #[test]
+    fn disable() {
+        let base = TextFormat {
+            italics: true,
+            underline: Stroke::new(1.0, Color32::RED),
+            strikethrough: Stroke::new(1.0, Color32::RED),
+            ..TextFormat::default()
+        };
+        let style = "not italic not underline not strike"
+            .parse::<Style>()
+            .unwrap();
+        let fmt = text_format(style, &Palette::XTERM, base);
+        assert!(!fmt.italics);
+        assert_eq!(fmt.underline, Stroke::NONE);
+        assert_eq!(fmt.strikethrough, Stroke::NONE);
+    }
+
+    #[test]
+    fn custom_palette() {
+        let mut base = Palette::XTERM.base_colors();
+        base[4] = RgbColor(0x45, 0x85, 0x88);
+        let palette = Palette::new(base);
+        let style = "bold on blue".parse::<Style>().unwrap();
+        let fmt = text_format(style, &palette, TextFormat::default());
+        assert_eq!(fmt.color, TextFormat::default().color);
+        assert_eq!(fmt.background, Color32::from_rgb(0x45, 0x85, 0x88));
+    }
+}
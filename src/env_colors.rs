@@ -0,0 +1,598 @@
+//! Parsing color-configuration environment variables
+//!
+//! Many command-line tools let users configure their colors through an
+//! environment variable containing a colon-separated list of `KEY=SGR`
+//! entries, such as `GREP_COLORS="ms=01;31:fn=35"` or
+//! `GCC_COLORS="error=01;31:warning=01;35"`.  [`EnvColors`] is a generic
+//! parser for this format, and [`ColorVar`] provides presets for the
+//! variables of several well-known tools, complete with their default styles,
+//! so that wrapper tools can honor whatever the user has already configured.
+//!
+//! `LS_COLORS`, which has its own key syntax and a companion database format,
+//! is handled by the [`ls_colors`][crate::ls_colors] module instead.
+//!
+//! # Example
+//!
+//! ```
+//! use parse_style::{Color256, Style};
+//! use parse_style::env_colors::ColorVar;
+//!
+//! let colors = ColorVar::GREP_COLORS.parse("mt=01;32:fn=34:ne").unwrap();
+//! assert_eq!(colors.get("ms"), Some(Color256::GREEN.as_foreground().bold()));
+//! assert_eq!(colors.get("fn"), Some(Color256::BLUE.as_foreground()));
+//! // Defaults are used for keys that the user did not set:
+//! assert_eq!(colors.get("se"), Some(Color256::CYAN.as_foreground()));
+//! assert!(colors.has_flag("ne"));
+//! ```
+use crate::ansi::{AnsiParser, AnsiToken};
+use crate::ls_colors::{style_from_sgr, style_to_sgr};
+use crate::style::Style;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A mapping from keys to styles parsed from a color-configuration
+/// environment variable, along with any bare (valueless) flag keys
+///
+/// Entries are kept in the order in which they were added.  `EnvColors`
+/// values are parsed from `KEY=SGR` lists via [`FromStr`] and converted back
+/// via [`Display`][fmt::Display].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EnvColors {
+    styles: Vec<(String, Style)>,
+    flags: Vec<String>,
+}
+
+impl EnvColors {
+    /// Create a new, empty `EnvColors`
+    pub fn new() -> EnvColors {
+        EnvColors::default()
+    }
+
+    /// Return the number of styles.
+    ///
+    /// Like [`EnvColors::is_empty()`] and [`EnvColors::iter()`], this only
+    /// considers styles; flags are not counted.
+    pub fn len(&self) -> usize {
+        self.styles.len()
+    }
+
+    /// Test whether there are no styles.  Flags are not considered; use
+    /// [`EnvColors::flags()`] to check for them.
+    pub fn is_empty(&self) -> bool {
+        self.styles.is_empty()
+    }
+
+    /// Return the style for the given key, if any
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.styles
+            .iter()
+            .find_map(|(k, style)| (k == key).then_some(*style))
+    }
+
+    /// Set the style for the given key, returning the previous style.  If the
+    /// key is already present, its entry keeps its position.
+    pub fn insert<K: Into<String>>(&mut self, key: K, style: Style) -> Option<Style> {
+        let key = key.into();
+        if let Some((_, s)) = self.styles.iter_mut().find(|(k, _)| *k == key) {
+            Some(std::mem::replace(s, style))
+        } else {
+            self.styles.push((key, style));
+            None
+        }
+    }
+
+    /// Remove the style for the given key and return it
+    pub fn remove(&mut self, key: &str) -> Option<Style> {
+        let i = self.styles.iter().position(|(k, _)| k == key)?;
+        Some(self.styles.remove(i).1)
+    }
+
+    /// Return an iterator over the keys and styles, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> + '_ {
+        self.styles.iter().map(|(k, style)| (k.as_str(), *style))
+    }
+
+    /// Test whether the given bare flag key (e.g., `ne` in `GREP_COLORS`) is
+    /// set
+    pub fn has_flag(&self, key: &str) -> bool {
+        self.flags.iter().any(|k| k == key)
+    }
+
+    /// Set or unset a bare flag key
+    pub fn set_flag<K: Into<String>>(&mut self, key: K, enabled: bool) {
+        let key = key.into();
+        if !enabled {
+            self.flags.retain(|k| *k != key);
+        } else if !self.has_flag(&key) {
+            self.flags.push(key);
+        }
+    }
+
+    /// Return an iterator over the set flag keys, in order
+    pub fn flags(&self) -> impl Iterator<Item = &str> + '_ {
+        self.flags.iter().map(String::as_str)
+    }
+
+    /// Read the styles from the `LESS_TERMCAP_*` environment variables used
+    /// by `less` to style man pages and other formatted text
+    ///
+    /// Each variable, if set, contains a literal escape sequence — e.g.,
+    /// `LESS_TERMCAP_md=$'\e[01;31m'` — and the returned `EnvColors` maps the
+    /// capability name (`md`, `us`, etc.) to the style that the sequence
+    /// produces.  Only the "start" capabilities are read: `mb` (blink), `md`
+    /// (bold), `mh` (dim), `mr` (reverse), `so` (standout), and `us`
+    /// (underline).  Variables that are unset or that contain anything other
+    /// than SGR sequences are skipped.
+    pub fn from_less_termcap_env() -> EnvColors {
+        let mut colors = EnvColors::new();
+        for cap in ["mb", "md", "mh", "mr", "so", "us"] {
+            if let Some(style) = std::env::var(format!("LESS_TERMCAP_{cap}"))
+                .ok()
+                .and_then(|value| style_from_escape(&value))
+            {
+                colors.insert(cap, style);
+            }
+        }
+        colors
+    }
+}
+
+impl FromStr for EnvColors {
+    type Err = ParseEnvColorsError;
+
+    /// Parse a colon-separated list of `KEY=SGR` entries.  Entries without
+    /// an `=` are treated as flags, empty entries are ignored, and if a key
+    /// occurs more than once, the last value is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value is not a valid list of SGR parameters.
+    fn from_str(s: &str) -> Result<EnvColors, ParseEnvColorsError> {
+        let mut colors = EnvColors::new();
+        for (key, value) in parse_entries(s) {
+            match value {
+                Some(value) => {
+                    colors.insert(key, parse_value(key, value)?);
+                }
+                None => colors.set_flag(key, true),
+            }
+        }
+        Ok(colors)
+    }
+}
+
+impl fmt::Display for EnvColors {
+    /// Format as a colon-separated list of `KEY=SGR` entries followed by the
+    /// flags
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (key, style) in &self.styles {
+            if !std::mem::replace(&mut first, false) {
+                write!(f, ":")?;
+            }
+            write!(f, "{key}={}", style_to_sgr(*style))?;
+        }
+        for key in &self.flags {
+            if !std::mem::replace(&mut first, false) {
+                write!(f, ":")?;
+            }
+            write!(f, "{key}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, Style)> for EnvColors {
+    fn from_iter<I: IntoIterator<Item = (K, Style)>>(iter: I) -> EnvColors {
+        let mut colors = EnvColors::new();
+        colors.extend(iter);
+        colors
+    }
+}
+
+impl<K: Into<String>> Extend<(K, Style)> for EnvColors {
+    fn extend<I: IntoIterator<Item = (K, Style)>>(&mut self, iter: I) {
+        for (key, style) in iter {
+            self.insert(key, style);
+        }
+    }
+}
+
+/// A preset describing the color-configuration environment variable of a
+/// particular tool: its name, its keys, and their default styles
+///
+/// Parsing a value with [`ColorVar::parse()`] or reading the variable with
+/// [`ColorVar::from_env()`] starts from the tool's defaults and applies the
+/// user's settings on top.  Keys that the tool does not know about are kept
+/// as-is.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ColorVar {
+    name: &'static str,
+    /// The keys and their default SGR parameters
+    defaults: &'static [(&'static str, &'static str)],
+    /// Keys that set several other keys at once
+    aliases: &'static [(&'static str, &'static [&'static str])],
+    /// Whether the value is a colon-separated list of SGR parameters in the
+    /// order of `defaults` rather than a list of `KEY=SGR` entries
+    positional: bool,
+}
+
+impl ColorVar {
+    /// `GREP_COLORS`, used by GNU `grep`
+    ///
+    /// The `mt` key sets both `ms` and `mc`.  The `rv` and `ne` capabilities
+    /// are flags.
+    pub const GREP_COLORS: ColorVar = ColorVar {
+        name: "GREP_COLORS",
+        defaults: &[
+            ("ms", "01;31"),
+            ("mc", "01;31"),
+            ("sl", ""),
+            ("cx", ""),
+            ("fn", "35"),
+            ("ln", "32"),
+            ("bn", "32"),
+            ("se", "36"),
+        ],
+        aliases: &[("mt", &["ms", "mc"])],
+        positional: false,
+    };
+
+    /// `GCC_COLORS`, used by GCC for diagnostics
+    pub const GCC_COLORS: ColorVar = ColorVar {
+        name: "GCC_COLORS",
+        defaults: &[
+            ("error", "01;31"),
+            ("warning", "01;35"),
+            ("note", "01;36"),
+            ("range1", "32"),
+            ("range2", "34"),
+            ("locus", "01"),
+            ("quote", "01"),
+            ("path", "01;36"),
+            ("fixit-insert", "32"),
+            ("fixit-delete", "31"),
+            ("diff-filename", "01"),
+            ("diff-hunk", "32"),
+            ("diff-delete", "31"),
+            ("diff-insert", "32"),
+            ("type-diff", "01;32"),
+            ("fnname", "01;32"),
+            ("targs", "35"),
+        ],
+        aliases: &[],
+        positional: false,
+    };
+
+    /// `EXA_COLORS`, used by `exa`
+    ///
+    /// In addition to the keys listed here, `exa` accepts the file type codes
+    /// and glob patterns of `LS_COLORS`, which are kept like any other key.
+    pub const EXA_COLORS: ColorVar = ColorVar {
+        name: "EXA_COLORS",
+        defaults: EXA_DEFAULTS,
+        aliases: &[],
+        positional: false,
+    };
+
+    /// `EZA_COLORS`, used by `eza`, the successor to `exa`, with the same keys
+    /// as [`ColorVar::EXA_COLORS`]
+    pub const EZA_COLORS: ColorVar = ColorVar {
+        name: "EZA_COLORS",
+        ..ColorVar::EXA_COLORS
+    };
+
+    /// `JQ_COLORS`, used by `jq`
+    ///
+    /// The value of this variable is a colon-separated list of SGR parameters
+    /// for, in order, `null`, `false`, `true`, `numbers`, `strings`,
+    /// `arrays`, `objects`, and `objkeys`.  As in jq 1.7.1, whose defaults
+    /// are used, only the fields that are present are applied: trailing
+    /// fields may be omitted, a trailing `:` does not add an empty field, an
+    /// empty value leaves all of the defaults in place, and fields after the
+    /// eighth are ignored.  An empty field in the middle of the list resets
+    /// its key to the empty style.
+    pub const JQ_COLORS: ColorVar = ColorVar {
+        name: "JQ_COLORS",
+        defaults: &[
+            ("null", "1;30"),
+            ("false", "0;39"),
+            ("true", "0;39"),
+            ("numbers", "0;39"),
+            ("strings", "0;32"),
+            ("arrays", "1;39"),
+            ("objects", "1;39"),
+            ("objkeys", "34;1"),
+        ],
+        aliases: &[],
+        positional: true,
+    };
+
+    /// Return the name of the environment variable
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Return an iterator over the keys known to the tool, in the tool's
+    /// order
+    pub fn keys(self) -> impl Iterator<Item = &'static str> {
+        self.defaults.iter().map(|&(key, _)| key)
+    }
+
+    /// Return the tool's default styles
+    pub fn defaults(self) -> EnvColors {
+        self.defaults
+            .iter()
+            .map(|&(key, sgr)| (key, style_from_sgr(sgr).unwrap_or_default()))
+            .collect()
+    }
+
+    /// Parse a value of the variable, applying it on top of the defaults
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value is not a valid list of SGR parameters.
+    pub fn parse(self, value: &str) -> Result<EnvColors, ParseEnvColorsError> {
+        let mut colors = self.defaults();
+        if self.positional {
+            let mut rest = value;
+            for key in self.keys() {
+                if rest.is_empty() {
+                    break;
+                }
+                let (field, tail) = rest.split_once(':').unwrap_or((rest, ""));
+                colors.insert(key, parse_value(key, field)?);
+                rest = tail;
+            }
+        } else {
+            for (key, value) in parse_entries(value) {
+                let Some(value) = value else {
+                    colors.set_flag(key, true);
+                    continue;
+                };
+                let style = parse_value(key, value)?;
+                match self.aliases.iter().find(|&&(alias, _)| alias == key) {
+                    Some(&(_, targets)) => colors.extend(targets.iter().map(|&k| (k, style))),
+                    None => {
+                        colors.insert(key, style);
+                    }
+                }
+            }
+        }
+        Ok(colors)
+    }
+
+    /// Read the variable from the environment and parse it with
+    /// [`ColorVar::parse()`].  If the variable is unset, empty, or not valid
+    /// Unicode, the defaults are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable's value fails to parse.
+    pub fn from_env(self) -> Result<EnvColors, ParseEnvColorsError> {
+        match std::env::var(self.name) {
+            Ok(value) if !value.is_empty() => self.parse(&value),
+            _ => Ok(self.defaults()),
+        }
+    }
+}
+
+static EXA_DEFAULTS: &[(&str, &str)] = &[
+    ("di", "01;34"),
+    ("ex", "01;32"),
+    ("fi", "0"),
+    ("pi", "33"),
+    ("so", "01;35"),
+    ("bd", "01;33"),
+    ("cd", "01;33"),
+    ("ln", "36"),
+    ("or", "31"),
+    ("ur", "01;33"),
+    ("uw", "01;31"),
+    ("ux", "01;4;32"),
+    ("ue", "01;32"),
+    ("gr", "33"),
+    ("gw", "31"),
+    ("gx", "32"),
+    ("tr", "33"),
+    ("tw", "31"),
+    ("tx", "32"),
+    ("su", "35"),
+    ("sf", "35"),
+    ("sn", "01;32"),
+    ("sb", "32"),
+    ("uu", "01;33"),
+    ("un", "0"),
+    ("gu", "01;33"),
+    ("gn", "0"),
+    ("ga", "32"),
+    ("gm", "34"),
+    ("gd", "31"),
+    ("gv", "33"),
+    ("gt", "35"),
+    ("da", "34"),
+    ("in", "35"),
+    ("bl", "36"),
+    ("hd", "4"),
+    ("lp", "36"),
+];
+
+/// Split a colon-separated list of `KEY=VALUE` entries, skipping empty
+/// entries.  Entries without an `=` are returned with a value of `None`.
+fn parse_entries(s: &str) -> impl Iterator<Item = (&str, Option<&str>)> + '_ {
+    s.split(':')
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once('=') {
+            Some((key, value)) => (key, Some(value)),
+            None => (entry, None),
+        })
+}
+
+fn parse_value(key: &str, value: &str) -> Result<Style, ParseEnvColorsError> {
+    style_from_sgr(value).ok_or_else(|| ParseEnvColorsError::Value {
+        key: key.to_owned(),
+        value: value.to_owned(),
+    })
+}
+
+/// Return the effective style produced by a string consisting solely of SGR
+/// escape sequences
+fn style_from_escape(s: &str) -> Option<Style> {
+    let mut parser = AnsiParser::new();
+    let mut tokens = parser.feed(s.as_bytes());
+    tokens.extend(parser.finish());
+    let mut style = None;
+    for token in tokens {
+        match token {
+            AnsiToken::Sgr(st) => style = Some(st),
+            AnsiToken::Text { .. } | AnsiToken::Escape(_) => return None,
+        }
+    }
+    style
+}
+
+/// Error returned when parsing a color-configuration environment variable
+/// fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseEnvColorsError {
+    /// A value was not a valid list of SGR parameters
+    #[error("invalid SGR parameters for {key:?}: {value:?}")]
+    Value {
+        /// The key
+        key: String,
+
+        /// The invalid value
+        value: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color256, RgbColor};
+    use rstest::rstest;
+
+    #[test]
+    fn parse_generic() {
+        let colors = "a=01;31::b=:c=38;2;1;2;3:flag:a=4"
+            .parse::<EnvColors>()
+            .unwrap();
+        assert_eq!(
+            colors.iter().collect::<Vec<_>>(),
+            [
+                ("a", Style::new().underline()),
+                ("b", Style::new()),
+                ("c", RgbColor(1, 2, 3).as_foreground()),
+            ]
+        );
+        assert!(colors.has_flag("flag"));
+        assert!(!colors.has_flag("a"));
+        assert_eq!(colors.to_string(), "a=4:b=0:c=38;2;1;2;3:flag");
+    }
+
+    #[test]
+    fn parse_generic_error() {
+        assert_eq!(
+            "a=1:b=bold".parse::<EnvColors>(),
+            Err(ParseEnvColorsError::Value {
+                key: "b".into(),
+                value: "bold".into()
+            })
+        );
+    }
+
+    #[test]
+    fn flags() {
+        let mut colors = EnvColors::new();
+        assert!(colors.is_empty());
+        colors.set_flag("rv", true);
+        colors.set_flag("ne", true);
+        colors.set_flag("rv", true);
+        assert!(colors.is_empty());
+        assert_eq!(colors.len(), 0);
+        assert_eq!(colors.flags().collect::<Vec<_>>(), ["rv", "ne"]);
+        colors.set_flag("rv", false);
+        assert_eq!(colors.to_string(), "ne");
+    }
+
+    #[rstest]
+    #[case(ColorVar::GREP_COLORS)]
+    #[case(ColorVar::GCC_COLORS)]
+    #[case(ColorVar::EXA_COLORS)]
+    #[case(ColorVar::JQ_COLORS)]
+    fn valid_defaults(#[case] var: ColorVar) {
+        for &(key, sgr) in var.defaults {
+            assert!(style_from_sgr(sgr).is_some(), "{key}={sgr}");
+        }
+    }
+
+    #[test]
+    fn grep_defaults() {
+        let colors = ColorVar::GREP_COLORS.defaults();
+        assert_eq!(colors.get("ms"), Some(Color256::RED.as_foreground().bold()));
+        assert_eq!(colors.get("sl"), Some(Style::new()));
+        assert_eq!(colors.get("mt"), None);
+        assert_eq!(
+            colors.to_string(),
+            "ms=1;31:mc=1;31:sl=0:cx=0:fn=35:ln=32:bn=32:se=36"
+        );
+    }
+
+    #[test]
+    fn grep_alias() {
+        let colors = ColorVar::GREP_COLORS.parse("ms=33:mt=34:mc=35").unwrap();
+        assert_eq!(colors.get("ms"), Some(Color256::BLUE.as_foreground()));
+        assert_eq!(colors.get("mc"), Some(Color256::MAGENTA.as_foreground()));
+    }
+
+    #[test]
+    fn gcc_unknown_key() {
+        let colors = ColorVar::GCC_COLORS.parse("error=01;91:my-key=7").unwrap();
+        assert_eq!(
+            colors.get("error"),
+            Some(Color256::BRIGHT_RED.as_foreground().bold())
+        );
+        assert_eq!(colors.get("my-key"), Some(Style::new().reverse()));
+        assert_eq!(colors.len(), ColorVar::GCC_COLORS.keys().count() + 1);
+    }
+
+    #[test]
+    fn eza() {
+        assert_eq!(ColorVar::EZA_COLORS.name(), "EZA_COLORS");
+        assert_eq!(
+            ColorVar::EZA_COLORS.defaults(),
+            ColorVar::EXA_COLORS.defaults()
+        );
+        let colors = ColorVar::EZA_COLORS.parse("*.rs=33:ur=0").unwrap();
+        assert_eq!(colors.get("*.rs"), Some(Color256::YELLOW.as_foreground()));
+        assert_eq!(colors.get("ur"), Some(Style::new()));
+    }
+
+    #[rstest]
+    #[case("", "null", Color256::BLACK.as_foreground().bold())]
+    #[case("0;31", "null", Color256::RED.into())]
+    #[case("0;31", "false", Style::new())]
+    #[case("0;31", "objects", Style::new().bold())]
+    #[case("1:2:3:4:5:", "arrays", Style::new().bold())]
+    #[case("1:2:3:4:5::", "arrays", Style::new())]
+    #[case(":::::::4", "objkeys", Style::new().underline())]
+    #[case(":::::::4", "strings", Style::new())]
+    #[case("::::1;33", "strings", Color256::YELLOW.as_foreground().bold())]
+    #[case("1:2:3:4:5:6:7:8:9", "objkeys", Style::new().conceal())]
+    fn jq(#[case] value: &str, #[case] key: &str, #[case] style: Style) {
+        let colors = ColorVar::JQ_COLORS.parse(value).unwrap();
+        assert_eq!(colors.get(key), Some(style));
+    }
+
+    #[rstest]
+    #[case("\x1b[01;31m", Some(Color256::RED.as_foreground().bold()))]
+    #[case("\x1b[1m\x1b[4;32m", Some(Color256::GREEN.as_foreground().bold().underline()))]
+    #[case("\x1b[0m", Some(Style::new()))]
+    #[case("", None)]
+    #[case("\x1b[1mX", None)]
+    #[case("\x1b[2J", None)]
+    fn escape(#[case] s: &str, #[case] style: Option<Style>) {
+        assert_eq!(style_from_escape(s), style);
+    }
+}
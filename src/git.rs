@@ -0,0 +1,341 @@
+use crate::attributes::{Attribute, AttributeSet};
+use crate::color::Color;
+use crate::color256::Color256;
+use crate::rgbcolor::RgbColor;
+use crate::style::Style;
+use crate::util::{parse_hex_rgb, strip_nocase_prefix};
+use std::fmt;
+use thiserror::Error;
+
+/// The names of the basic colors in Git's color syntax
+static COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The attributes recognized by Git, along with their names
+static ATTRIBUTES: [(&str, Attribute); 7] = [
+    ("bold", Attribute::Bold),
+    ("dim", Attribute::Dim),
+    ("italic", Attribute::Italic),
+    ("ul", Attribute::Underline),
+    ("blink", Attribute::Blink),
+    ("reverse", Attribute::Reverse),
+    ("strike", Attribute::Strike),
+];
+
+impl Style {
+    /// Parse a color value in the syntax used by `git config` (e.g., for the
+    /// `color.diff.*` settings)
+    ///
+    /// A Git color value is a whitespace-separated list of words, each of
+    /// which is one of the following (case-insensitive):
+    ///
+    /// - A color: `normal` (leave the color unchanged), `default` (which
+    ///   becomes [`Color::Default`]), one of the eight basic color names, a
+    ///   basic color name prefixed with `bright` (e.g., `brightred`), an
+    ///   integer from 0 to 255, `-1` (same as `normal`), or a hex RGB color of
+    ///   the form `#rrggbb` or `#rgb`.  The first color given is the
+    ///   foreground, and the second is the background.
+    ///
+    /// - An attribute: `bold`, `dim`, `italic`, `ul` (underline), `blink`,
+    ///   `reverse`, or `strike`.  An attribute prefixed with `no` or `no-`
+    ///   (e.g., `nobold` or `no-ul`) is disabled.
+    ///
+    /// - `reset`, which resets all colors and attributes before the others
+    ///   are applied.  The resulting style sets any color not given to
+    ///   `Color::Default` and disables all of Git's attributes that are not
+    ///   enabled.
+    ///
+    /// An empty string parses to an empty style.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a word is not recognized or if more than two
+    /// colors are given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Color, Color256, RgbColor, Style};
+    ///
+    /// assert_eq!(
+    ///     Style::parse_git_color("bold red blue").unwrap(),
+    ///     Color256::RED.on(Color256::BLUE).bold(),
+    /// );
+    /// assert_eq!(
+    ///     Style::parse_git_color("normal #ff0000 no-ul").unwrap(),
+    ///     RgbColor(255, 0, 0).as_background().not_underline(),
+    /// );
+    /// assert_eq!(
+    ///     Style::parse_git_color("default 208").unwrap(),
+    ///     Color::Default.on(Color256(208)),
+    /// );
+    /// ```
+    pub fn parse_git_color(s: &str) -> Result<Style, ParseGitColorError> {
+        let mut colors = Vec::with_capacity(2);
+        let mut enabled = AttributeSet::EMPTY;
+        let mut disabled = AttributeSet::EMPTY;
+        let mut reset = false;
+        for word in s.split_whitespace() {
+            if word.eq_ignore_ascii_case("reset") {
+                reset = true;
+            } else if let Some(color) = parse_git_color_word(word) {
+                if colors.len() == 2 {
+                    return Err(ParseGitColorError::ExtraColor(word.to_owned()));
+                }
+                colors.push(color);
+            } else if let Some(attr) = parse_git_attribute(word) {
+                enabled |= attr;
+                disabled -= attr;
+            } else if let Some(attr) = strip_nocase_prefix(word, "no")
+                .map(|w| strip_nocase_prefix(w, "-").unwrap_or(w))
+                .and_then(parse_git_attribute)
+            {
+                disabled |= attr;
+                enabled -= attr;
+            } else {
+                return Err(ParseGitColorError::Word(word.to_owned()));
+            }
+        }
+        let mut colors = colors.into_iter();
+        let mut fg = colors.next().flatten();
+        let mut bg = colors.next().flatten();
+        if reset {
+            fg = fg.or(Some(Color::Default));
+            bg = bg.or(Some(Color::Default));
+            disabled = ATTRIBUTES
+                .iter()
+                .fold(AttributeSet::EMPTY, |acc, &(_, attr)| acc | attr)
+                - enabled;
+        }
+        Ok(Style::new()
+            .foreground(fg)
+            .background(bg)
+            .enabled_attributes(enabled)
+            .disabled_attributes(disabled))
+    }
+
+    /// Return a value that displays the style in the color syntax used by
+    /// `git config`; see [`GitStyle`]
+    pub fn display_git(self) -> GitStyle {
+        GitStyle(self)
+    }
+}
+
+/// Parse a word of a Git color value as a color.  `normal` and `-1` are
+/// returned as `Some(None)`.
+fn parse_git_color_word(word: &str) -> Option<Option<Color>> {
+    if word.eq_ignore_ascii_case("normal") || word == "-1" {
+        Some(None)
+    } else if word.eq_ignore_ascii_case("default") {
+        Some(Some(Color::Default))
+    } else if let Some(hex) = word.strip_prefix('#') {
+        parse_hex_rgb(hex).map(|rgb| Some(Color::from(rgb)))
+    } else if let Ok(index) = word.parse::<u8>() {
+        Some(Some(Color::from(Color256(index))))
+    } else {
+        let (name, offset) = match strip_nocase_prefix(word, "bright") {
+            Some(name) => (name, 8),
+            None => (word, 0),
+        };
+        let index = COLOR_NAMES
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(name))?;
+        let index = u8::try_from(index).ok()? + offset;
+        Some(Some(Color::from(Color256(index))))
+    }
+}
+
+fn parse_git_attribute(word: &str) -> Option<Attribute> {
+    ATTRIBUTES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(word))
+        .map(|&(_, attr)| attr)
+}
+
+/// A wrapper around a [`Style`] that displays it in the color syntax used by
+/// `git config`
+///
+/// Enabled attributes are written first, followed by disabled attributes
+/// (with a `no` prefix), the foreground color, and the background color.  If
+/// the background is set but the foreground is not, the foreground is written
+/// as `normal`, and an empty style is written as just `normal`.  The output
+/// can be parsed back with [`Style::parse_git_color()`].
+///
+/// # Data Loss
+///
+/// [`Attribute::Blink2`] is written as `blink`, and [`Attribute::Underline2`]
+/// is written as `ul`.
+///
+/// The following attributes are not supported by Git and are omitted:
+///
+/// - [`Attribute::Conceal`]
+/// - [`Attribute::Frame`]
+/// - [`Attribute::Encircle`]
+/// - [`Attribute::Overline`]
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, RgbColor, Style};
+///
+/// let style = Color256::BRIGHT_GREEN.on(RgbColor(0x12, 0x34, 0x56)).bold().not_italic();
+/// assert_eq!(style.display_git().to_string(), "bold noitalic brightgreen #123456");
+/// assert_eq!(Style::from(Color256(208).as_background()).display_git().to_string(), "normal 208");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GitStyle(Style);
+
+impl GitStyle {
+    /// Return the wrapped `Style`
+    pub const fn style(self) -> Style {
+        self.0
+    }
+}
+
+impl From<Style> for GitStyle {
+    fn from(value: Style) -> GitStyle {
+        GitStyle(value)
+    }
+}
+
+impl fmt::Display for GitStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.0;
+        let mut words = Vec::new();
+        let git_attrs = |attrs: AttributeSet| {
+            ATTRIBUTES.iter().filter_map(move |&(name, attr)| {
+                let variant = match attr {
+                    Attribute::Underline => Some(Attribute::Underline2),
+                    Attribute::Blink => Some(Attribute::Blink2),
+                    _ => None,
+                };
+                (attrs.contains(attr) || variant.is_some_and(|v| attrs.contains(v))).then_some(name)
+            })
+        };
+        words.extend(git_attrs(style.get_enabled_attributes()).map(String::from));
+        words.extend(git_attrs(style.get_disabled_attributes()).map(|name| format!("no{name}")));
+        let fg = style.get_foreground();
+        let bg = style.get_background();
+        if fg.is_some() || bg.is_some() || words.is_empty() {
+            words.push(fg.map_or_else(|| String::from("normal"), git_color_name));
+        }
+        if let Some(bg) = bg {
+            words.push(git_color_name(bg));
+        }
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+fn git_color_name(color: Color) -> String {
+    match color {
+        Color::Default => String::from("default"),
+        Color::Color256(Color256(n @ 0..=7)) => COLOR_NAMES[usize::from(n)].to_owned(),
+        Color::Color256(Color256(n @ 8..=15)) => {
+            format!("bright{}", COLOR_NAMES[usize::from(n - 8)])
+        }
+        Color::Color256(Color256(n)) => n.to_string(),
+        Color::Rgb(RgbColor(r, g, b)) => format!("#{r:02x}{g:02x}{b:02x}"),
+    }
+}
+
+/// Error returned when parsing a Git color value fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseGitColorError {
+    /// A word was neither a color, an attribute, nor `reset`
+    #[error("unrecognized word in git color: {0:?}")]
+    Word(
+        /// The unrecognized word
+        String,
+    ),
+
+    /// More than two colors were given
+    #[error("too many colors in git color: {0:?}")]
+    ExtraColor(
+        /// The first extra color
+        String,
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", Style::new())]
+    #[case("normal", Style::new())]
+    #[case("normal normal", Style::new())]
+    #[case("-1 blue", Color256::BLUE.as_background())]
+    #[case("red", Color256::RED.as_foreground())]
+    #[case("RED Bold", Color256::RED.as_foreground().bold())]
+    #[case("bold red blue", Color256::RED.on(Color256::BLUE).bold())]
+    #[case("red bold blue", Color256::RED.on(Color256::BLUE).bold())]
+    #[case("brightyellow brightblack", Color256::BRIGHT_YELLOW.on(Color256::BRIGHT_BLACK))]
+    #[case("ul", Style::new().underline())]
+    #[case("nobold no-ul NoItalic", Style::new().not_bold().not_underline().not_italic())]
+    #[case("bold nobold", Style::new().not_bold())]
+    #[case("nobold bold", Style::new().bold())]
+    #[case("dim blink reverse strike", Style::new().dim().blink().reverse().strike())]
+    #[case("#ff0000", RgbColor(255, 0, 0).as_foreground())]
+    #[case("#F0a", RgbColor(0xff, 0x00, 0xaa).as_foreground())]
+    #[case("0 255", Color256::BLACK.on(Color256(255)))]
+    #[case("default default", Color::Default.on(Color::Default))]
+    #[case(
+        "reset bold green",
+        Color256::GREEN
+            .on(Color::Default)
+            .bold()
+            .not_dim()
+            .not_italic()
+            .not_underline()
+            .not_blink()
+            .not_reverse()
+            .not_strike()
+    )]
+    fn parse(#[case] s: &str, #[case] style: Style) {
+        assert_eq!(Style::parse_git_color(s), Ok(style));
+    }
+
+    #[rstest]
+    #[case("red blue green", ParseGitColorError::ExtraColor("green".into()))]
+    #[case("normal normal normal", ParseGitColorError::ExtraColor("normal".into()))]
+    #[case("underline", ParseGitColorError::Word("underline".into()))]
+    #[case("not bold", ParseGitColorError::Word("not".into()))]
+    #[case("256", ParseGitColorError::Word("256".into()))]
+    #[case("#ff00", ParseGitColorError::Word("#ff00".into()))]
+    #[case("#gggggg", ParseGitColorError::Word("#gggggg".into()))]
+    #[case("bright", ParseGitColorError::Word("bright".into()))]
+    #[case("no-", ParseGitColorError::Word("no-".into()))]
+    #[case("on red", ParseGitColorError::Word("on".into()))]
+    fn parse_error(#[case] s: &str, #[case] err: ParseGitColorError) {
+        assert_eq!(Style::parse_git_color(s), Err(err));
+    }
+
+    #[rstest]
+    #[case(Style::new(), "normal")]
+    #[case(Style::new().bold(), "bold")]
+    #[case(Color256::RED.as_foreground(), "red")]
+    #[case(Color256::RED.on(Color256::BRIGHT_BLUE), "red brightblue")]
+    #[case(Color::Default.as_background(), "normal default")]
+    #[case(Style::new().underline2().blink().blink2(), "ul blink")]
+    #[case(Style::new().not_underline().not_underline2(), "noul")]
+    #[case(Style::new().overline().not_conceal(), "normal")]
+    #[case(
+        RgbColor(0xab, 0xcd, 0xef).on(Color256(100)).italic().not_strike(),
+        "italic nostrike #abcdef 100"
+    )]
+    fn display(#[case] style: Style, #[case] s: &str) {
+        assert_eq!(style.display_git().to_string(), s);
+    }
+
+    #[rstest]
+    #[case(Style::new())]
+    #[case(Color256::BRIGHT_CYAN.on(Color::Default).bold().not_dim())]
+    #[case(Color256(42).on(RgbColor(1, 2, 3)).reverse().strike())]
+    #[case(Color256::MAGENTA.as_background().not_italic())]
+    fn roundtrip(#[case] style: Style) {
+        let s = style.display_git().to_string();
+        assert_eq!(Style::parse_git_color(&s), Ok(style));
+    }
+}
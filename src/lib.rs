@@ -19,10 +19,10 @@
 //! assert_eq!(style.to_string(), "underline bright_green");
 //! ```
 //!
-//! Note that this library does not provide functionality for rendering styles
-//! as ANSI escape sequences; there are plenty of crates that do that already,
-//! and `parse-style` provides conversions to some of those crates' types so
-//! you can use them for your actual styling.
+//! For writing styled text to a terminal, [`StyledWriter`] emits the minimal
+//! ANSI escape sequences needed to switch between styles.  Alternatively,
+//! `parse-style` provides conversions to the types of a number of terminal
+//! styling crates so that you can use them for your actual styling.
 //!
 //! Style String Syntax
 //! ===================
@@ -101,12 +101,64 @@
 //! - `anstyle` — Enables conversions between `parse-style` types and types
 //!   from the [`anstyle`](https://crates.io/crates/anstyle) crate
 //!
+//! - `colored` — Enables conversions between `parse-style` types and types from
+//!   the [`colored`](https://crates.io/crates/colored) crate
+//!
+//! - `console` — Enables conversions between `parse-style` types and types from
+//!   the [`console`](https://crates.io/crates/console) crate
+//!
 //! - `crossterm` — Enables conversions between `parse-style` types and types
 //!   from the [`crossterm`](https://crates.io/crates/crossterm) crate
 //!
+//! - `cursive` — Enables conversions between `parse-style` types and types from
+//!   the [`cursive`](https://crates.io/crates/cursive) crate (via
+//!   [`cursive_core`](https://crates.io/crates/cursive_core))
+//!
+//! - `egui` — Enables conversions between `parse-style` types and types from
+//!   the [`egui`](https://crates.io/crates/egui) crate (via
+//!   [`epaint`](https://crates.io/crates/epaint)), along with a function for
+//!   converting styles to text formats using a custom palette
+//!
+//! - `nu-ansi-term` — Enables conversions between `parse-style` types and
+//!   types from the [`nu-ansi-term`](https://crates.io/crates/nu-ansi-term)
+//!   crate
+//!
+//! - `owo-colors` — Enables conversions between `parse-style` types and types
+//!   from the [`owo-colors`](https://crates.io/crates/owo-colors) crate
+//!
+//! - `palette` — Enables conversions between `parse-style` color types and
+//!   color types from the [`palette`](https://crates.io/crates/palette) crate
+//!
+//! - `plist` — Enables loading terminal color schemes from iTerm2
+//!   `.itermcolors` files in the `scheme` module using the
+//!   [`plist`](https://crates.io/crates/plist) crate
+//!
 //! - `ratatui` — Enables conversions between `parse-style` types and types
 //!   from the [`ratatui-core`](https://crates.io/crates/ratatui-core) crate
 //!
+//! - `syntect` — Enables conversions between `parse-style` types and types from
+//!   the [`syntect`](https://crates.io/crates/syntect) crate, along with a
+//!   function for building `syntect` themes from style strings
+//!
+//! - `termcolor` — Enables conversions between `parse-style` types and types
+//!   from the [`termcolor`](https://crates.io/crates/termcolor) crate
+//!
+//! - `termion` — Enables conversions between `parse-style` types and types from
+//!   the [`termion`](https://crates.io/crates/termion) crate, along with a
+//!   `TermionStyle` type for writing styles using `termion`.  This feature
+//!   only has an effect on Unix platforms.
+//!
+//! - `toml` — Enables loading terminal color schemes from Alacritty and
+//!   WezTerm TOML files in the `scheme` module using the
+//!   [`toml`](https://crates.io/crates/toml) crate
+//!
+//! - `yaml` — Enables loading terminal color schemes from base16 YAML files in
+//!   the `scheme` module using the
+//!   [`yaml-rust2`](https://crates.io/crates/yaml-rust2) crate
+//!
+//! - `yansi` — Enables conversions between `parse-style` types and types from
+//!   the [`yansi`](https://crates.io/crates/yansi) crate
+//!
 //! - `serde` — Enables [`serde`](https://serde.rs) implementations for
 //!   (de)serializing `Style` values as style strings and colors as color
 //!   strings.  When combined with one or more of the above features, also
@@ -125,22 +177,57 @@
 mod attributes;
 mod color;
 mod color256;
+mod colorspace;
+mod contrast;
+mod git;
+mod palette;
 mod rgbcolor;
+mod sgr;
 mod style;
+mod text;
+mod tmux;
 mod util;
+mod vim;
+mod writer;
 pub use crate::attributes::{
     Attribute, AttributeIter, AttributeSet, AttributeSetIter, ParseAttributeError,
 };
 pub use crate::color::Color;
 pub use crate::color256::Color256;
-pub use crate::rgbcolor::RgbColor;
+pub use crate::colorspace::{Hsl, Hsv, Lab, Oklab, Oklch};
+pub use crate::contrast::Readability;
+pub use crate::git::{GitStyle, ParseGitColorError};
+pub use crate::palette::Palette;
+pub use crate::rgbcolor::{Gradient, RgbColor};
+#[cfg(all(unix, feature = "termion"))]
+pub use crate::style::TermionStyle;
 pub use crate::style::{ParseStyleError, Style};
+pub use crate::text::{Segments, StyledText};
+pub use crate::tmux::{ParseTmuxStyleError, TmuxStyle};
+pub use crate::vim::{ColorDepth, ParseVimHighlightError, VimHighlight};
+pub use crate::writer::StyledWriter;
 use thiserror::Error;
 
+pub mod ansi;
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+pub mod egui;
+
+pub mod env_colors;
+
+pub mod ls_colors;
+
+pub mod scheme;
+
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde;
 
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+pub mod syntect;
+
 /// Error returned when parsing a color string fails
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 #[error("invalid color string: {0:?}")]
@@ -0,0 +1,684 @@
+//! Parsing & serializing `LS_COLORS` and `dircolors` databases
+//!
+//! The `LS_COLORS` environment variable, used by GNU `ls` and many other
+//! tools, is a colon-separated list of `KEY=VALUE` entries, where each key is
+//! either a two-letter file type code (e.g., `di` for directories) or a glob
+//! pattern for file names (e.g., `*.tar`), and each value is a list of SGR
+//! parameters (e.g., `01;34`).  The same information can also be written in
+//! the line-based format read by the `dircolors` command.
+//!
+//! [`LsColors`] holds such a mapping from keys to [`Style`]s and can be
+//! converted to & from both formats.
+//!
+//! # Example
+//!
+//! ```
+//! use parse_style::{Color256, Style};
+//! use parse_style::ls_colors::LsColors;
+//!
+//! let colors = "di=01;34:ln=target:*.tar=01;31".parse::<LsColors>().unwrap();
+//! assert_eq!(colors.get("di"), Some(Color256::BLUE.as_foreground().bold()));
+//! assert!(colors.is_link_target());
+//! assert_eq!(
+//!     colors.style_for_name("backup.tar"),
+//!     Some(Color256::RED.as_foreground().bold())
+//! );
+//!
+//! let dircolors = colors.to_dircolors();
+//! assert_eq!(dircolors, "DIR 1;34\nLINK target\n*.tar 1;31\n");
+//! assert_eq!(LsColors::parse_dircolors(&dircolors), Ok(colors));
+//! ```
+use crate::sgr::{apply_sgr_params, effective, split_sgr_params, style_sgr_params};
+use crate::style::Style;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The two-letter file type codes recognized in `LS_COLORS`, along with their
+/// canonical `dircolors` keywords
+static FILE_TYPES: [(&str, &str); 24] = [
+    ("no", "NORMAL"),
+    ("fi", "FILE"),
+    ("rs", "RESET"),
+    ("di", "DIR"),
+    ("ln", "LINK"),
+    ("mh", "MULTIHARDLINK"),
+    ("pi", "FIFO"),
+    ("so", "SOCK"),
+    ("do", "DOOR"),
+    ("bd", "BLK"),
+    ("cd", "CHR"),
+    ("or", "ORPHAN"),
+    ("mi", "MISSING"),
+    ("su", "SETUID"),
+    ("sg", "SETGID"),
+    ("ca", "CAPABILITY"),
+    ("tw", "STICKY_OTHER_WRITABLE"),
+    ("ow", "OTHER_WRITABLE"),
+    ("st", "STICKY"),
+    ("ex", "EXEC"),
+    ("lc", "LEFTCODE"),
+    ("rc", "RIGHTCODE"),
+    ("ec", "ENDCODE"),
+    ("cl", "CLRTOEOL"),
+];
+
+/// Keys whose values are raw terminal codes rather than SGR parameters
+static TERMINAL_CODE_KEYS: [&str; 4] = ["lc", "rc", "ec", "cl"];
+
+/// Alternative `dircolors` keywords accepted by GNU `dircolors`
+static KEYWORD_ALIASES: [(&str, &str); 13] = [
+    ("NORM", "no"),
+    ("LNK", "ln"),
+    ("SYMLINK", "ln"),
+    ("PIPE", "pi"),
+    ("BLOCK", "bd"),
+    ("CHAR", "cd"),
+    ("LEFT", "lc"),
+    ("RIGHT", "rc"),
+    ("END", "ec"),
+    ("SUID", "su"),
+    ("SGID", "sg"),
+    ("OWT", "tw"),
+    ("OWR", "ow"),
+];
+
+/// `dircolors` keywords that do not set a color and are ignored when parsing
+static IGNORED_KEYWORDS: [&str; 5] = ["TERM", "COLORTERM", "COLOR", "OPTIONS", "EIGHTBIT"];
+
+/// A mapping from `LS_COLORS` keys to styles
+///
+/// Keys are either two-letter file type codes like `"di"` or glob patterns
+/// starting with `*` like `"*.tar"`.  Entries are kept in the order in which
+/// they were added.
+///
+/// Styles are stored in their effective form: parsing `01;34` produces a
+/// bold blue style, and parsing `0` or an empty value produces an empty style.
+/// When serializing, an empty style is written as `0`.
+///
+/// The special value `target` for the `ln` key, which tells `ls` to style
+/// symbolic links like the files they point to, is also supported; see
+/// [`LsColors::is_link_target()`].
+///
+/// The `lc`, `rc`, `ec`, and `cl` keys hold raw terminal codes that `ls`
+/// emits around styled file names.  Values for these keys that are not lists of
+/// SGR parameters (e.g., `ec=\e[0m`) are kept verbatim; they are not returned
+/// by [`LsColors::get()`] or [`LsColors::iter()`], but they are preserved when
+/// serializing.
+///
+/// `LsColors` values are parsed from `LS_COLORS` strings via [`FromStr`] and
+/// converted back to `LS_COLORS` strings via [`Display`][fmt::Display].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LsColors {
+    entries: Vec<(String, Value)>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Value {
+    Style(Style),
+    /// `ln=target`
+    Target,
+    /// A verbatim terminal code for `lc`, `rc`, `ec`, or `cl`
+    Raw(String),
+}
+
+impl LsColors {
+    /// Create a new, empty `LsColors`
+    pub fn new() -> LsColors {
+        LsColors::default()
+    }
+
+    /// Return the number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the style for the given key, if any
+    pub fn get(&self, key: &str) -> Option<Style> {
+        self.entries.iter().find_map(|(k, v)| match v {
+            Value::Style(style) if k == key => Some(*style),
+            _ => None,
+        })
+    }
+
+    /// Set the style for the given key, returning the previous style.  If the
+    /// key is already present, its entry keeps its position.
+    ///
+    /// Setting a style for `"ln"` turns off [`LsColors::is_link_target()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is neither a recognized file type code nor a glob
+    /// pattern starting with `*`.
+    pub fn insert<K: Into<String>>(&mut self, key: K, style: Style) -> Option<Style> {
+        let key = key.into();
+        assert!(is_valid_key(&key), "invalid LS_COLORS key: {key:?}");
+        self.set(key, Value::Style(effective(style)))
+    }
+
+    /// Remove the entry for the given key, returning its style
+    pub fn remove(&mut self, key: &str) -> Option<Style> {
+        let i = self.entries.iter().position(|(k, _)| k == key)?;
+        match self.entries.remove(i).1 {
+            Value::Style(style) => Some(style),
+            Value::Target | Value::Raw(_) => None,
+        }
+    }
+
+    /// Test whether symbolic links are styled like the files they point to,
+    /// i.e., whether the `ln` key has the value `target`
+    pub fn is_link_target(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|(k, v)| k == "ln" && *v == Value::Target)
+    }
+
+    /// Set whether symbolic links are styled like the files they point to.
+    /// Enabling this replaces any style for the `"ln"` key, and disabling it
+    /// when enabled removes the `"ln"` entry.
+    pub fn set_link_target(&mut self, enabled: bool) {
+        if enabled {
+            self.set(String::from("ln"), Value::Target);
+        } else if self.is_link_target() {
+            self.entries.retain(|(k, _)| k != "ln");
+        }
+    }
+
+    /// Return an iterator over the keys and styles, in order.  An `ln=target`
+    /// entry and verbatim terminal codes are not included.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> + '_ {
+        self.entries.iter().filter_map(|(k, v)| match v {
+            Value::Style(style) => Some((k.as_str(), *style)),
+            Value::Target | Value::Raw(_) => None,
+        })
+    }
+
+    /// Return the style for a file with the given name according to the
+    /// glob entries, if any match.
+    ///
+    /// Like GNU `ls`, only patterns of the form `*SUFFIX` are supported, and
+    /// later entries take precedence over earlier ones.  Suffixes are matched
+    /// case-sensitively first; if no entry matches, they are matched
+    /// ASCII-case-insensitively.
+    pub fn style_for_name(&self, name: &str) -> Option<Style> {
+        let globs = || {
+            self.entries.iter().rev().filter_map(|(k, v)| {
+                let suffix = k.strip_prefix('*')?;
+                match v {
+                    Value::Style(style) if suffix.len() <= name.len() => Some((suffix, *style)),
+                    _ => None,
+                }
+            })
+        };
+        globs()
+            .find(|(suffix, _)| name.ends_with(suffix))
+            .or_else(|| {
+                globs().find(|(suffix, _)| {
+                    name.get(name.len() - suffix.len()..)
+                        .is_some_and(|end| end.eq_ignore_ascii_case(suffix))
+                })
+            })
+            .map(|(_, style)| style)
+    }
+
+    /// Parse a database in the format read by the `dircolors` command
+    ///
+    /// Each non-blank line consists of a keyword and a value separated by
+    /// whitespace; `#` starts a comment that runs to the end of the line.
+    /// Keywords are either file type names like `DIR` (case-insensitive),
+    /// glob patterns starting with `*`, or file extensions starting with `.`
+    /// (which are equivalent to the same extension preceded by `*`).
+    ///
+    /// `TERM`, `COLORTERM`, `COLOR`, `OPTIONS`, and `EIGHTBIT` lines are
+    /// ignored, and so entries are read from every `TERM` section regardless
+    /// of the current terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line does not consist of exactly a keyword and a
+    /// value, if a keyword is not recognized, or if a value for a key other
+    /// than `lc`, `rc`, `ec`, or `cl` is not a valid list of SGR parameters.
+    pub fn parse_dircolors(s: &str) -> Result<LsColors, ParseLsColorsError> {
+        let mut colors = LsColors::new();
+        for (i, line) in s.lines().enumerate() {
+            let mut words = line
+                .split_whitespace()
+                .take_while(|word| !word.starts_with('#'));
+            let Some(keyword) = words.next() else {
+                continue;
+            };
+            if IGNORED_KEYWORDS
+                .iter()
+                .any(|kw| kw.eq_ignore_ascii_case(keyword))
+            {
+                continue;
+            }
+            let (Some(value), None) = (words.next(), words.next()) else {
+                return Err(ParseLsColorsError::Line {
+                    lineno: i + 1,
+                    line: line.to_owned(),
+                });
+            };
+            let key = if keyword.starts_with('*') {
+                keyword.to_owned()
+            } else if keyword.starts_with('.') {
+                format!("*{keyword}")
+            } else if let Some(code) = keyword_code(keyword) {
+                code.to_owned()
+            } else {
+                return Err(ParseLsColorsError::Key(keyword.to_owned()));
+            };
+            colors.add_parsed(key, value)?;
+        }
+        Ok(colors)
+    }
+
+    /// Serialize in the format read by the `dircolors` command, one entry per
+    /// line
+    pub fn to_dircolors(&self) -> String {
+        let mut s = String::new();
+        for (key, value) in &self.entries {
+            let keyword = FILE_TYPES
+                .iter()
+                .find(|&&(code, _)| code == key)
+                .map_or(key.as_str(), |&(_, name)| name);
+            s.push_str(keyword);
+            s.push(' ');
+            s.push_str(&value.to_string());
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Set the value for `key`, returning the previous style
+    fn set(&mut self, key: String, value: Value) -> Option<Style> {
+        if let Some((_, v)) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            match std::mem::replace(v, value) {
+                Value::Style(style) => Some(style),
+                Value::Target | Value::Raw(_) => None,
+            }
+        } else {
+            self.entries.push((key, value));
+            None
+        }
+    }
+
+    /// Add an entry with the unparsed value `value` for the already-validated
+    /// key `key`
+    fn add_parsed(&mut self, key: String, value: &str) -> Result<(), ParseLsColorsError> {
+        let value = if key == "ln" && value == "target" {
+            Value::Target
+        } else if let Some(style) = style_from_sgr(value) {
+            Value::Style(style)
+        } else if TERMINAL_CODE_KEYS.contains(&key.as_str()) {
+            Value::Raw(value.to_owned())
+        } else {
+            return Err(ParseLsColorsError::Value {
+                key,
+                value: value.to_owned(),
+            });
+        };
+        self.set(key, value);
+        Ok(())
+    }
+}
+
+impl FromStr for LsColors {
+    type Err = ParseLsColorsError;
+
+    /// Parse an `LS_COLORS` string.  Empty entries are ignored, and if a key
+    /// occurs more than once, the last value is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry does not contain `=`, if a key is not
+    /// recognized, or if a value for a key other than `lc`, `rc`, `ec`, or `cl`
+    /// is not a valid list of SGR parameters.
+    fn from_str(s: &str) -> Result<LsColors, ParseLsColorsError> {
+        let mut colors = LsColors::new();
+        for entry in s.split(':').filter(|entry| !entry.is_empty()) {
+            let Some((key, value)) = entry.split_once('=') else {
+                return Err(ParseLsColorsError::Entry(entry.to_owned()));
+            };
+            if !is_valid_key(key) {
+                return Err(ParseLsColorsError::Key(key.to_owned()));
+            }
+            colors.add_parsed(key.to_owned(), value)?;
+        }
+        Ok(colors)
+    }
+}
+
+impl fmt::Display for LsColors {
+    /// Format as an `LS_COLORS` string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, ":")?;
+            }
+            write!(f, "{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Style(style) => write!(f, "{}", style_sgr_params(*style)),
+            Value::Target => write!(f, "target"),
+            Value::Raw(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, Style)> for LsColors {
+    /// Build an `LsColors` from key-style pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is invalid; see [`LsColors::insert()`].
+    fn from_iter<I: IntoIterator<Item = (K, Style)>>(iter: I) -> LsColors {
+        let mut colors = LsColors::new();
+        colors.extend(iter);
+        colors
+    }
+}
+
+impl<K: Into<String>> Extend<(K, Style)> for LsColors {
+    /// Insert key-style pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is invalid; see [`LsColors::insert()`].
+    fn extend<I: IntoIterator<Item = (K, Style)>>(&mut self, iter: I) {
+        for (key, style) in iter {
+            self.insert(key, style);
+        }
+    }
+}
+
+/// Parse an `LS_COLORS`-style value — a semicolon-separated list of SGR
+/// parameters, such as `01;34` — into the effective [`Style`] that it
+/// produces.  Empty parameters are treated as 0.
+///
+/// Returns `None` if the value contains anything other than ASCII digits and
+/// semicolons.  Unrecognized SGR parameters are ignored.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style};
+/// use parse_style::ls_colors::style_from_sgr;
+///
+/// assert_eq!(
+///     style_from_sgr("01;38;5;208"),
+///     Some(Color256(208).as_foreground().bold())
+/// );
+/// assert_eq!(style_from_sgr("0"), Some(Style::new()));
+/// assert_eq!(style_from_sgr("bold"), None);
+/// ```
+pub fn style_from_sgr(params: &str) -> Option<Style> {
+    params
+        .bytes()
+        .all(|b| b.is_ascii_digit() || b == b';')
+        .then(|| effective(apply_sgr_params(Style::new(), split_sgr_params(params))))
+}
+
+/// Convert a [`Style`] to an `LS_COLORS`-style list of SGR parameters.
+///
+/// Disabled attributes and [`Color::Default`][crate::Color::Default] colors
+/// are omitted, and an empty style is converted to `"0"`.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style};
+/// use parse_style::ls_colors::style_to_sgr;
+///
+/// assert_eq!(style_to_sgr(Color256::BLUE.as_foreground().bold()), "1;34");
+/// assert_eq!(style_to_sgr(Style::new()), "0");
+/// ```
+pub fn style_to_sgr(style: Style) -> String {
+    style_sgr_params(style)
+}
+
+/// Return the file type code for a case-insensitive `dircolors` keyword
+fn keyword_code(keyword: &str) -> Option<&'static str> {
+    FILE_TYPES
+        .iter()
+        .map(|&(code, name)| (name, code))
+        .chain(KEYWORD_ALIASES)
+        .find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+        .map(|(_, code)| code)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    key.starts_with('*') || FILE_TYPES.iter().any(|&(code, _)| code == key)
+}
+
+/// Error returned when parsing an `LS_COLORS` string or `dircolors` database
+/// fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseLsColorsError {
+    /// An `LS_COLORS` entry did not contain `=`
+    #[error("LS_COLORS entry is missing '=': {0:?}")]
+    Entry(
+        /// The invalid entry
+        String,
+    ),
+
+    /// A key or `dircolors` keyword was not recognized
+    #[error("unrecognized LS_COLORS key: {0:?}")]
+    Key(
+        /// The unrecognized key
+        String,
+    ),
+
+    /// A value was not a valid list of SGR parameters
+    #[error("invalid SGR parameters for {key:?}: {value:?}")]
+    Value {
+        /// The key, normalized to its `LS_COLORS` form
+        key: String,
+
+        /// The invalid value
+        value: String,
+    },
+
+    /// A `dircolors` line did not consist of exactly a keyword and a value
+    #[error("invalid dircolors line {lineno}: {line:?}")]
+    Line {
+        /// The 1-based number of the line
+        lineno: usize,
+
+        /// The invalid line
+        line: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color256, RgbColor};
+    use rstest::rstest;
+
+    #[test]
+    fn parse_ls_colors() {
+        let colors = "rs=0:di=01;34:ln=01;36:mh=00:*.tar=01;31:*.TAR=1;33:"
+            .parse::<LsColors>()
+            .unwrap();
+        assert_eq!(
+            colors.iter().collect::<Vec<_>>(),
+            [
+                ("rs", Style::new()),
+                ("di", Color256::BLUE.as_foreground().bold()),
+                ("ln", Color256::CYAN.as_foreground().bold()),
+                ("mh", Style::new()),
+                ("*.tar", Color256::RED.as_foreground().bold()),
+                ("*.TAR", Color256::YELLOW.as_foreground().bold()),
+            ]
+        );
+        assert!(!colors.is_link_target());
+        assert_eq!(
+            colors.to_string(),
+            "rs=0:di=1;34:ln=1;36:mh=0:*.tar=1;31:*.TAR=1;33"
+        );
+    }
+
+    #[test]
+    fn terminal_codes() {
+        let colors = "lc=\\e[:rc=m:ec=\\e[0m:di=01;34"
+            .parse::<LsColors>()
+            .unwrap();
+        assert_eq!(
+            colors.iter().collect::<Vec<_>>(),
+            [("di", Color256::BLUE.as_foreground().bold())]
+        );
+        assert_eq!(colors.get("ec"), None);
+        assert_eq!(colors.len(), 4);
+        assert_eq!(colors.to_string(), "lc=\\e[:rc=m:ec=\\e[0m:di=1;34");
+        assert_eq!(
+            colors.to_dircolors(),
+            "LEFTCODE \\e[\nRIGHTCODE m\nENDCODE \\e[0m\nDIR 1;34\n"
+        );
+        assert_eq!(
+            LsColors::parse_dircolors(&colors.to_dircolors()),
+            Ok(colors)
+        );
+    }
+
+    #[test]
+    fn duplicate_key() {
+        let colors = "di=31:ex=32:di=33".parse::<LsColors>().unwrap();
+        assert_eq!(colors.to_string(), "di=33:ex=32");
+    }
+
+    #[rstest]
+    #[case("di", ParseLsColorsError::Entry("di".into()))]
+    #[case("zz=01", ParseLsColorsError::Key("zz".into()))]
+    #[case("DIR=01", ParseLsColorsError::Key("DIR".into()))]
+    #[case("di=target", ParseLsColorsError::Value { key: "di".into(), value: "target".into() })]
+    #[case("di=\\e[0m", ParseLsColorsError::Value { key: "di".into(), value: "\\e[0m".into() })]
+    fn parse_ls_colors_error(#[case] s: &str, #[case] err: ParseLsColorsError) {
+        assert_eq!(s.parse::<LsColors>(), Err(err));
+    }
+
+    #[test]
+    fn parse_dircolors() {
+        let db = concat!(
+            "# Configuration file for dircolors\n",
+            "COLOR tty\n",
+            "TERM xterm*\n",
+            "\n",
+            "RESET 0 # reset to \"normal\" color\n",
+            "dir 01;34\n",
+            "LINK target\n",
+            "  OWR 34;42\n",
+            ".tar 01;31\n",
+            "*README 04\n",
+            "EXEC 38;2;255;128;0\n",
+        );
+        let colors = LsColors::parse_dircolors(db).unwrap();
+        assert_eq!(
+            colors.to_string(),
+            "rs=0:di=1;34:ln=target:ow=34;42:*.tar=1;31:*README=4:ex=38;2;255;128;0"
+        );
+        assert_eq!(
+            colors.get("ex"),
+            Some(RgbColor(255, 128, 0).as_foreground())
+        );
+        assert_eq!(
+            colors.to_dircolors(),
+            concat!(
+                "RESET 0\n",
+                "DIR 1;34\n",
+                "LINK target\n",
+                "OTHER_WRITABLE 34;42\n",
+                "*.tar 1;31\n",
+                "*README 4\n",
+                "EXEC 38;2;255;128;0\n",
+            )
+        );
+    }
+
+    #[rstest]
+    #[case("DIR", 1)]
+    #[case("DIR 01 34", 1)]
+    #[case("\n# comment\nFILE 00\nDIR # 01;34", 4)]
+    fn parse_dircolors_bad_line(#[case] db: &str, #[case] lineno: usize) {
+        assert!(matches!(
+            LsColors::parse_dircolors(db),
+            Err(ParseLsColorsError::Line { lineno: n, .. }) if n == lineno
+        ));
+    }
+
+    #[test]
+    fn parse_dircolors_bad_keyword() {
+        assert_eq!(
+            LsColors::parse_dircolors("DIRECTORY 01;34"),
+            Err(ParseLsColorsError::Key("DIRECTORY".into()))
+        );
+    }
+
+    #[rstest]
+    #[case("archive.tar", Some(Color256::RED.as_foreground()))]
+    #[case("ARCHIVE.TAR", Some(Color256::YELLOW.as_foreground()))]
+    #[case("archive.Tar", Some(Color256::YELLOW.as_foreground()))]
+    #[case("archive.tar.gz", Some(Color256::GREEN.as_foreground()))]
+    #[case("README", Some(Style::new().underline()))]
+    #[case("tar", None)]
+    #[case("", None)]
+    fn style_for_name(#[case] name: &str, #[case] style: Option<Style>) {
+        let colors = "*.tar=31:*.TAR=33:*.gz=32:*README=4"
+            .parse::<LsColors>()
+            .unwrap();
+        assert_eq!(colors.style_for_name(name), style);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut colors = LsColors::new();
+        assert_eq!(colors.insert("di", Color256::BLUE.into()), None);
+        colors.set_link_target(true);
+        assert_eq!(
+            colors.insert("*.rs", Style::new().bold().not_italic()),
+            None
+        );
+        assert_eq!(colors.get("ln"), None);
+        assert_eq!(colors.to_string(), "di=34:ln=target:*.rs=1");
+        assert_eq!(colors.insert("ln", Color256::CYAN.into()), None);
+        assert!(!colors.is_link_target());
+        assert_eq!(
+            colors.insert("di", Color256::RED.into()),
+            Some(Color256::BLUE.into())
+        );
+        assert_eq!(colors.remove("ln"), Some(Color256::CYAN.into()));
+        assert_eq!(colors.remove("ln"), None);
+        assert_eq!(colors.to_string(), "di=31:*.rs=1");
+        colors.set_link_target(false);
+        assert_eq!(colors.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid LS_COLORS key")]
+    fn insert_invalid_key() {
+        LsColors::new().insert("directory", Style::new());
+    }
+
+    #[rstest]
+    #[case("", Style::new())]
+    #[case("0", Style::new())]
+    #[case("00;31", Color256::RED.into())]
+    #[case("1;22", Style::new())]
+    #[case("31;39", Style::new())]
+    #[case("4;97;100", Color256::BRIGHT_WHITE.on(Color256::BRIGHT_BLACK).underline())]
+    fn sgr_roundtrip(#[case] params: &str, #[case] style: Style) {
+        assert_eq!(style_from_sgr(params), Some(style));
+        assert_eq!(style_from_sgr(&style_to_sgr(style)), Some(style));
+    }
+}
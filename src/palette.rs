@@ -0,0 +1,134 @@
+use crate::color::Color;
+use crate::color256::Color256;
+use crate::rgbcolor::RgbColor;
+
+/// A mapping from [`Color256`] values to the RGB colors that a terminal
+/// actually displays them as
+///
+/// A `Palette` stores the RGB values of the sixteen base colors (indices 0
+/// through 15), which vary between terminals and themes.  The remaining colors
+/// (indices 16 through 255) are always resolved using the standard xterm
+/// formulas for the 6×6×6 color cube and the greyscale ramp.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Palette, RgbColor};
+///
+/// let palette = Palette::XTERM;
+/// assert_eq!(palette.get(Color256::RED), RgbColor(0x80, 0x00, 0x00));
+/// assert_eq!(palette.get(Color256(208)), RgbColor(0xFF, 0x87, 0x00));
+/// assert_eq!(palette.get(Color256(244)), RgbColor(0x80, 0x80, 0x80));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Palette {
+    base: [RgbColor; 16],
+}
+
+impl Palette {
+    /// The standard xterm palette, whose base colors are the values listed in
+    /// [`rich`'s color table][table]
+    ///
+    /// [table]: https://rich.readthedocs.io/en/stable/appendix/colors.html
+    pub const XTERM: Palette = Palette::new([
+        RgbColor(0x00, 0x00, 0x00),
+        RgbColor(0x80, 0x00, 0x00),
+        RgbColor(0x00, 0x80, 0x00),
+        RgbColor(0x80, 0x80, 0x00),
+        RgbColor(0x00, 0x00, 0x80),
+        RgbColor(0x80, 0x00, 0x80),
+        RgbColor(0x00, 0x80, 0x80),
+        RgbColor(0xC0, 0xC0, 0xC0),
+        RgbColor(0x80, 0x80, 0x80),
+        RgbColor(0xFF, 0x00, 0x00),
+        RgbColor(0x00, 0xFF, 0x00),
+        RgbColor(0xFF, 0xFF, 0x00),
+        RgbColor(0x00, 0x00, 0xFF),
+        RgbColor(0xFF, 0x00, 0xFF),
+        RgbColor(0x00, 0xFF, 0xFF),
+        RgbColor(0xFF, 0xFF, 0xFF),
+    ]);
+
+    /// Create a new `Palette` with the given RGB values for colors 0 through
+    /// 15
+    pub const fn new(base: [RgbColor; 16]) -> Palette {
+        Palette { base }
+    }
+
+    /// Return the RGB values for colors 0 through 15
+    pub const fn base_colors(&self) -> [RgbColor; 16] {
+        self.base
+    }
+
+    /// Return the RGB color that the given [`Color256`] is displayed as
+    pub fn get(&self, color: Color256) -> RgbColor {
+        const CUBE_LEVELS: [u8; 6] = [0x00, 0x5F, 0x87, 0xAF, 0xD7, 0xFF];
+        match color.0 {
+            i @ 0..16 => self.base[usize::from(i)],
+            i @ 16..232 => {
+                let i = usize::from(i - 16);
+                RgbColor(
+                    CUBE_LEVELS[i / 36],
+                    CUBE_LEVELS[(i / 6) % 6],
+                    CUBE_LEVELS[i % 6],
+                )
+            }
+            i => {
+                let level = 8 + (i - 232) * 10;
+                RgbColor(level, level, level)
+            }
+        }
+    }
+
+    /// Return the RGB color that the given [`Color`] is displayed as, using
+    /// `default` for [`Color::Default`]
+    pub fn resolve(&self, color: Color, default: RgbColor) -> RgbColor {
+        match color {
+            Color::Default => default,
+            Color::Color256(c) => self.get(c),
+            Color::Rgb(c) => c,
+        }
+    }
+}
+
+impl Default for Palette {
+    /// Returns [`Palette::XTERM`]
+    fn default() -> Palette {
+        Palette::XTERM
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, RgbColor(0x00, 0x00, 0x00))]
+    #[case(9, RgbColor(0xFF, 0x00, 0x00))]
+    #[case(16, RgbColor(0x00, 0x00, 0x00))]
+    #[case(21, RgbColor(0x00, 0x00, 0xFF))]
+    #[case(118, RgbColor(0x87, 0xFF, 0x00))]
+    #[case(196, RgbColor(0xFF, 0x00, 0x00))]
+    #[case(231, RgbColor(0xFF, 0xFF, 0xFF))]
+    #[case(232, RgbColor(0x08, 0x08, 0x08))]
+    #[case(255, RgbColor(0xEE, 0xEE, 0xEE))]
+    fn xterm(#[case] index: u8, #[case] rgb: RgbColor) {
+        assert_eq!(Palette::XTERM.get(Color256(index)), rgb);
+    }
+
+    #[test]
+    fn resolve() {
+        let default = RgbColor(1, 2, 3);
+        let palette = Palette::default();
+        assert_eq!(palette.resolve(Color::Default, default), default);
+        assert_eq!(
+            palette.resolve(Color256::BLUE.into(), default),
+            RgbColor(0x00, 0x00, 0x80)
+        );
+        assert_eq!(
+            palette.resolve(RgbColor(4, 5, 6).into(), default),
+            RgbColor(4, 5, 6)
+        );
+    }
+}
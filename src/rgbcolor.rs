@@ -1,7 +1,8 @@
 use super::ParseColorError;
 use crate::color::Color;
+use crate::colorspace::srgb_to_linear;
 use crate::style::Style;
-use crate::util::strip_nocase_prefix;
+use crate::util::{component_from_f64, strip_nocase_prefix};
 use std::fmt;
 
 /// A 24-bit color composed of red, green, and blue components
@@ -50,6 +51,125 @@ impl RgbColor {
         self.2
     }
 
+    /// Return the relative luminance of the color as defined by [WCAG
+    /// 2.x][wcag], ranging from 0.0 for black to 1.0 for white
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(self) -> f64 {
+        0.2126f64.mul_add(
+            srgb_to_linear(self.0),
+            0.7152f64.mul_add(srgb_to_linear(self.1), 0.0722 * srgb_to_linear(self.2)),
+        )
+    }
+
+    /// Return the [WCAG 2.x contrast ratio][wcag] between this color and
+    /// `other`, ranging from 1.0 for identical colors to 21.0 for black
+    /// against white
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::RgbColor;
+    ///
+    /// let black = RgbColor(0, 0, 0);
+    /// let white = RgbColor(255, 255, 255);
+    /// assert!((black.contrast_ratio(white) - 21.0).abs() < 1e-9);
+    /// assert!((white.contrast_ratio(black) - 21.0).abs() < 1e-9);
+    /// ```
+    pub fn contrast_ratio(self, other: RgbColor) -> f64 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// Mix this color with `other`.
+    ///
+    /// `t` is the proportion of `other` in the result, from 0.0 (just `self`)
+    /// to 1.0 (just `other`); values outside this range are clamped.  Each
+    /// component is interpolated linearly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::RgbColor;
+    ///
+    /// let black = RgbColor(0, 0, 0);
+    /// let orange = RgbColor(255, 128, 0);
+    /// assert_eq!(black.blend(orange, 0.5), RgbColor(128, 64, 0));
+    /// ```
+    pub fn blend(self, other: RgbColor, t: f64) -> RgbColor {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| {
+            let a = f64::from(a);
+            component_from_f64((f64::from(b) - a).mul_add(t, a))
+        };
+        RgbColor(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    /// Mix this color with white.  `amount` is the proportion of white in the
+    /// result, from 0.0 to 1.0.
+    pub fn lighten(self, amount: f64) -> RgbColor {
+        self.blend(RgbColor(255, 255, 255), amount)
+    }
+
+    /// Mix this color with black.  `amount` is the proportion of black in the
+    /// result, from 0.0 to 1.0.
+    pub fn darken(self, amount: f64) -> RgbColor {
+        self.blend(RgbColor(0, 0, 0), amount)
+    }
+
+    /// Adjust the saturation of this color by moving each component away from
+    /// (for positive `amount`) or towards (for negative `amount`) the grey of
+    /// the same luma.
+    ///
+    /// An `amount` of 0.0 leaves the color unchanged, and an `amount` of -1.0
+    /// produces a fully desaturated grey.  Components are clamped to the
+    /// range `0..=255`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::RgbColor;
+    ///
+    /// let color = RgbColor(200, 100, 100);
+    /// assert_eq!(color.saturate(-1.0), RgbColor(130, 130, 130));
+    /// assert_eq!(color.saturate(0.5), RgbColor(235, 85, 85));
+    /// ```
+    pub fn saturate(self, amount: f64) -> RgbColor {
+        let factor = (1.0 + amount).max(0.0);
+        let luma = 0.299f64.mul_add(
+            f64::from(self.0),
+            0.587f64.mul_add(f64::from(self.1), 0.114 * f64::from(self.2)),
+        );
+        let adjust = |c: u8| component_from_f64((f64::from(c) - luma).mul_add(factor, luma));
+        RgbColor(adjust(self.0), adjust(self.1), adjust(self.2))
+    }
+
+    /// Return an iterator of `n` colors evenly spaced between `from` and `to`,
+    /// inclusive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::RgbColor;
+    ///
+    /// let colors = RgbColor::gradient(RgbColor(0, 0, 0), RgbColor(255, 0, 100), 3)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     colors,
+    ///     [RgbColor(0, 0, 0), RgbColor(128, 0, 50), RgbColor(255, 0, 100)]
+    /// );
+    /// ```
+    pub fn gradient(from: RgbColor, to: RgbColor, n: usize) -> Gradient {
+        Gradient::new(from, to, n)
+    }
+
     /// Return a new [`Style`] that uses this color as the foreground color
     pub fn as_foreground(self) -> Style {
         Style::new().foreground(Some(self.into()))
@@ -67,8 +187,108 @@ impl RgbColor {
             .foreground(Some(self.into()))
             .background(Some(bg.into()))
     }
+
+    /// Parse a `#xxxxxx` or `rgb(INT,INT,INT)` string without allocating
+    pub(crate) fn parse_str(s: &str) -> Option<RgbColor> {
+        if let Some(hex) = s.strip_prefix('#') {
+            let &[r1, r2, g1, g2, b1, b2] = hex.as_bytes() else {
+                return None;
+            };
+            Some(RgbColor(
+                hex_pair(r1, r2)?,
+                hex_pair(g1, g2)?,
+                hex_pair(b1, b2)?,
+            ))
+        } else {
+            let dec = strip_nocase_prefix(s, "rgb(")?.strip_suffix(')')?;
+            let mut rgb = dec.split(',').map(str::parse::<u8>);
+            let red = rgb.next();
+            let green = rgb.next();
+            let blue = rgb.next();
+            let rest = rgb.next();
+            if let (Some(Ok(red)), Some(Ok(green)), Some(Ok(blue)), None) = (red, green, blue, rest)
+            {
+                Some(RgbColor(red, green, blue))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// An iterator over colors evenly spaced between two [`RgbColor`]s
+///
+/// This `struct` is returned by [`RgbColor::gradient()`].
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    from: RgbColor,
+    to: RgbColor,
+    steps: usize,
+    front: usize,
+    back: usize,
+}
+
+impl Gradient {
+    fn new(from: RgbColor, to: RgbColor, n: usize) -> Gradient {
+        Gradient {
+            from,
+            to,
+            steps: n.saturating_sub(1),
+            front: 0,
+            back: n,
+        }
+    }
+
+    fn color_at(&self, i: usize) -> RgbColor {
+        if self.steps == 0 {
+            return self.from;
+        }
+        // Use integer arithmetic to avoid precision loss from converting
+        // `usize` to `f64`
+        let steps = self.steps as u128;
+        let i = i as u128;
+        let mix = |a: u8, b: u8| {
+            let v = (u128::from(a) * (steps - i) + u128::from(b) * i + steps / 2) / steps;
+            u8::try_from(v).expect("interpolated component should fit in a u8")
+        };
+        RgbColor(
+            mix(self.from.0, self.to.0),
+            mix(self.from.1, self.to.1),
+            mix(self.from.2, self.to.2),
+        )
+    }
 }
 
+impl Iterator for Gradient {
+    type Item = RgbColor;
+
+    fn next(&mut self) -> Option<RgbColor> {
+        (self.front < self.back).then(|| {
+            let c = self.color_at(self.front);
+            self.front += 1;
+            c
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Gradient {
+    fn next_back(&mut self) -> Option<RgbColor> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.color_at(self.back)
+        })
+    }
+}
+
+impl ExactSizeIterator for Gradient {}
+
+impl std::iter::FusedIterator for Gradient {}
+
 impl From<(u8, u8, u8)> for RgbColor {
     fn from(value: (u8, u8, u8)) -> RgbColor {
         RgbColor(value.0, value.1, value.2)
@@ -99,6 +319,28 @@ impl From<anstyle::RgbColor> for RgbColor {
     }
 }
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<RgbColor> for colored::Color {
+    /// Convert an `RgbColor` to a [`colored::Color`]
+    fn from(value: RgbColor) -> colored::Color {
+        colored::Color::TrueColor {
+            r: value.0,
+            g: value.1,
+            b: value.2,
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl From<RgbColor> for console::Color {
+    /// Convert an `RgbColor` to a [`console::Color`]
+    fn from(value: RgbColor) -> console::Color {
+        console::Color::TrueColor(value.0, value.1, value.2)
+    }
+}
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 impl From<RgbColor> for crossterm::style::Color {
@@ -112,6 +354,56 @@ impl From<RgbColor> for crossterm::style::Color {
     }
 }
 
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<RgbColor> for cursive_core::style::Color {
+    /// Convert an `RgbColor` to a [`cursive_core::style::Color`]
+    fn from(value: RgbColor) -> cursive_core::style::Color {
+        cursive_core::style::Color::Rgb(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+impl From<RgbColor> for epaint::Color32 {
+    /// Convert an `RgbColor` to an opaque [`epaint::Color32`]
+    fn from(value: RgbColor) -> epaint::Color32 {
+        epaint::Color32::from_rgb(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+impl From<epaint::Color32> for RgbColor {
+    /// Convert an [`epaint::Color32`] to an `RgbColor`
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha component is discarded during conversion.
+    fn from(value: epaint::Color32) -> RgbColor {
+        let [r, g, b, _] = value.to_srgba_unmultiplied();
+        RgbColor(r, g, b)
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<RgbColor> for nu_ansi_term::Color {
+    /// Convert an `RgbColor` to a [`nu_ansi_term::Color`]
+    fn from(value: RgbColor) -> nu_ansi_term::Color {
+        nu_ansi_term::Color::Rgb(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<RgbColor> for owo_colors::DynColors {
+    /// Convert an `RgbColor` to an [`owo_colors::DynColors`]
+    fn from(value: RgbColor) -> owo_colors::DynColors {
+        owo_colors::DynColors::Rgb(value.0, value.1, value.2)
+    }
+}
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
 impl From<RgbColor> for ratatui_core::style::Color {
@@ -121,6 +413,117 @@ impl From<RgbColor> for ratatui_core::style::Color {
     }
 }
 
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl From<RgbColor> for palette::Srgb<u8> {
+    /// Convert an `RgbColor` to a [`palette::Srgb<u8>`]
+    fn from(value: RgbColor) -> palette::Srgb<u8> {
+        palette::Srgb::new(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl From<palette::Srgb<u8>> for RgbColor {
+    /// Convert a [`palette::Srgb<u8>`] to an `RgbColor`
+    fn from(value: palette::Srgb<u8>) -> RgbColor {
+        RgbColor(value.red, value.green, value.blue)
+    }
+}
+
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl From<RgbColor> for palette::Srgb<f32> {
+    /// Convert an `RgbColor` to a [`palette::Srgb<f32>`]
+    fn from(value: RgbColor) -> palette::Srgb<f32> {
+        palette::Srgb::<u8>::from(value).into_format()
+    }
+}
+
+#[cfg(feature = "palette")]
+#[cfg_attr(docsrs, doc(cfg(feature = "palette")))]
+impl From<palette::Srgb<f32>> for RgbColor {
+    /// Convert a [`palette::Srgb<f32>`] to an `RgbColor`
+    ///
+    /// # Data Loss
+    ///
+    /// Components are rounded to the nearest representable value, and
+    /// components outside the range 0.0 to 1.0 are clamped.
+    fn from(value: palette::Srgb<f32>) -> RgbColor {
+        RgbColor::from(value.into_format::<u8>())
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<RgbColor> for syntect::highlighting::Color {
+    /// Convert an `RgbColor` to an opaque [`syntect::highlighting::Color`]
+    fn from(value: RgbColor) -> syntect::highlighting::Color {
+        syntect::highlighting::Color {
+            r: value.0,
+            g: value.1,
+            b: value.2,
+            a: 0xFF,
+        }
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<syntect::highlighting::Color> for RgbColor {
+    /// Convert a [`syntect::highlighting::Color`] to an `RgbColor`
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha component is discarded during conversion.
+    fn from(value: syntect::highlighting::Color) -> RgbColor {
+        RgbColor(value.r, value.g, value.b)
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<RgbColor> for termcolor::Color {
+    /// Convert an `RgbColor` to a [`termcolor::Color`]
+    fn from(value: RgbColor) -> termcolor::Color {
+        termcolor::Color::Rgb(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+impl From<RgbColor> for termion::color::Rgb {
+    /// Convert an `RgbColor` to a [`termion::color::Rgb`]
+    fn from(value: RgbColor) -> termion::color::Rgb {
+        termion::color::Rgb(value.0, value.1, value.2)
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+impl termion::color::Color for RgbColor {
+    /// Write the escape sequence for setting the foreground color to this
+    /// color
+    fn write_fg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        termion::color::Color::write_fg(&termion::color::Rgb::from(*self), f)
+    }
+
+    /// Write the escape sequence for setting the background color to this
+    /// color
+    fn write_bg(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        termion::color::Color::write_bg(&termion::color::Rgb::from(*self), f)
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<RgbColor> for yansi::Color {
+    /// Convert an `RgbColor` to a [`yansi::Color`]
+    fn from(value: RgbColor) -> yansi::Color {
+        yansi::Color::Rgb(value.0, value.1, value.2)
+    }
+}
+
 impl fmt::Display for RgbColor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
@@ -131,32 +534,17 @@ impl std::str::FromStr for RgbColor {
     type Err = ParseColorError;
 
     fn from_str(s: &str) -> Result<RgbColor, ParseColorError> {
-        if let Some(hex) = s
-            .strip_prefix('#')
-            .filter(|s| s.chars().all(|c| c.is_ascii_hexdigit()) && s.len() == 6)
-        {
-            let red = u8::from_str_radix(&hex[..2], 16).expect("should be valid hex string");
-            let green = u8::from_str_radix(&hex[2..4], 16).expect("should be valid hex string");
-            let blue = u8::from_str_radix(&hex[4..], 16).expect("should be valid hex string");
-            Ok(RgbColor(red, green, blue))
-        } else if let Some(dec) = strip_nocase_prefix(s, "rgb(").and_then(|s| s.strip_suffix(')')) {
-            let mut rgb = dec.split(',').map(str::parse::<u8>);
-            let red = rgb.next();
-            let green = rgb.next();
-            let blue = rgb.next();
-            let rest = rgb.next();
-            if let (Some(Ok(red)), Some(Ok(green)), Some(Ok(blue)), None) = (red, green, blue, rest)
-            {
-                Ok(RgbColor(red, green, blue))
-            } else {
-                Err(ParseColorError(s.to_owned()))
-            }
-        } else {
-            Err(ParseColorError(s.to_owned()))
-        }
+        RgbColor::parse_str(s).ok_or_else(|| ParseColorError(s.to_owned()))
     }
 }
 
+/// Combine two ASCII hex digits into a byte
+fn hex_pair(hi: u8, lo: u8) -> Option<u8> {
+    let hi = char::from(hi).to_digit(16)?;
+    let lo = char::from(lo).to_digit(16)?;
+    u8::try_from(hi * 16 + lo).ok()
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl serde::Serialize for RgbColor {
@@ -197,6 +585,100 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), 0.0)]
+    #[case(RgbColor(255, 255, 255), 1.0)]
+    #[case(RgbColor(255, 0, 0), 0.2126)]
+    #[case(RgbColor(0, 255, 0), 0.7152)]
+    #[case(RgbColor(0, 0, 255), 0.0722)]
+    #[case(RgbColor(0x80, 0x80, 0x80), 0.215_861)]
+    fn test_relative_luminance(#[case] color: RgbColor, #[case] lum: f64) {
+        assert!((color.relative_luminance() - lum).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(RgbColor(0, 0, 0), RgbColor(0, 0, 0), 1.0)]
+    #[case(RgbColor(0, 0, 0), RgbColor(255, 255, 255), 21.0)]
+    #[case(RgbColor(0, 0, 0x80), RgbColor(0, 0, 0), 1.311_703)]
+    #[case(RgbColor(0x77, 0x77, 0x77), RgbColor(255, 255, 255), 4.478_089)]
+    fn test_contrast_ratio(#[case] c1: RgbColor, #[case] c2: RgbColor, #[case] ratio: f64) {
+        assert!((c1.contrast_ratio(c2) - ratio).abs() < 1e-6);
+        assert!((c2.contrast_ratio(c1) - ratio).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(0.0, RgbColor(10, 200, 30))]
+    #[case(0.25, RgbColor(40, 160, 35))]
+    #[case(1.0, RgbColor(130, 40, 50))]
+    #[case(-1.0, RgbColor(10, 200, 30))]
+    #[case(2.0, RgbColor(130, 40, 50))]
+    fn test_blend(#[case] t: f64, #[case] color: RgbColor) {
+        assert_eq!(RgbColor(10, 200, 30).blend(RgbColor(130, 40, 50), t), color);
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        let color = RgbColor(100, 50, 0);
+        assert_eq!(color.lighten(0.0), color);
+        assert_eq!(color.lighten(0.5), RgbColor(178, 153, 128));
+        assert_eq!(color.lighten(1.0), RgbColor(255, 255, 255));
+        assert_eq!(color.darken(0.0), color);
+        assert_eq!(color.darken(0.5), RgbColor(50, 25, 0));
+        assert_eq!(color.darken(1.0), RgbColor(0, 0, 0));
+    }
+
+    #[test]
+    fn test_saturate() {
+        let color = RgbColor(200, 100, 100);
+        assert_eq!(color.saturate(0.0), color);
+        assert_eq!(color.saturate(-1.0), RgbColor(130, 130, 130));
+        assert_eq!(color.saturate(-5.0), RgbColor(130, 130, 130));
+        assert_eq!(color.saturate(10.0), RgbColor(255, 0, 0));
+        let grey = RgbColor(77, 77, 77);
+        assert_eq!(grey.saturate(1.0), grey);
+    }
+
+    #[test]
+    fn test_gradient() {
+        let from = RgbColor(0, 100, 255);
+        let to = RgbColor(255, 0, 0);
+        assert_eq!(RgbColor::gradient(from, to, 0).next(), None);
+        assert_eq!(RgbColor::gradient(from, to, 1).collect::<Vec<_>>(), [from]);
+        assert_eq!(
+            RgbColor::gradient(from, to, 2).collect::<Vec<_>>(),
+            [from, to]
+        );
+        let mut grad = RgbColor::gradient(from, to, 6);
+        assert_eq!(grad.len(), 6);
+        assert_eq!(grad.next(), Some(from));
+        assert_eq!(grad.next_back(), Some(to));
+        assert_eq!(grad.next(), Some(RgbColor(51, 80, 204)));
+        assert_eq!(grad.next_back(), Some(RgbColor(204, 20, 51)));
+        assert_eq!(grad.len(), 2);
+        assert_eq!(grad.next(), Some(RgbColor(102, 60, 153)));
+        assert_eq!(grad.next(), Some(RgbColor(153, 40, 102)));
+        assert_eq!(grad.next(), None);
+        assert_eq!(grad.next_back(), None);
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_palette_round_trip() {
+        let color = RgbColor(0x7F, 0xFF, 0x00);
+        let srgb = palette::Srgb::<u8>::from(color);
+        assert_eq!(srgb, palette::Srgb::new(0x7F, 0xFF, 0x00));
+        assert_eq!(RgbColor::from(srgb), color);
+        let srgb = palette::Srgb::<f32>::from(color);
+        assert_eq!(RgbColor::from(srgb), color);
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_from_palette_f32() {
+        let srgb = palette::Srgb::new(0.5f32, 1.5, -0.25);
+        assert_eq!(RgbColor::from(srgb), RgbColor(128, 255, 0));
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(RgbColor(0x7F, 0xFF, 0x00).to_string(), "#7fff00");
@@ -215,6 +697,9 @@ mod tests {
     #[case("7fff00")]
     #[case("# 7fff00")]
     #[case("#000")]
+    #[case("#7fff0g")]
+    #[case("#+fff00")]
+    #[case("#7f\u{e9}0")]
     #[case("rgb(78, 126, 70)")]
     #[case("rgb(78,126)")]
     #[case("rgb(78,126,70,0)")]
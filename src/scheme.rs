@@ -0,0 +1,1097 @@
+//! Importing & exporting terminal color schemes
+//!
+//! Terminal emulator themes define the RGB values that the sixteen base
+//! colors — [`Color256`][crate::Color256] indices 0 through 15 — are
+//! actually displayed as, along with the default foreground and background
+//! colors.  A [`ColorScheme`] holds this information and can be loaded from
+//! and saved to several common text-based theme formats:
+//!
+//! - Xresources (`*.colorN`, `*.foreground`, `*.background`): loaded with
+//!   [`ColorScheme::from_xresources()`] and saved with
+//!   [`ColorScheme::to_xresources()`]
+//!
+//! - Alacritty TOML (`[colors.primary]`, `[colors.normal]`,
+//!   `[colors.bright]`): loaded with
+//!   [`ColorScheme::from_alacritty_toml()`][from_alacritty_toml] (requires
+//!   the `toml` feature) and saved with [`ColorScheme::to_alacritty_toml()`]
+//!
+//! - WezTerm TOML (`[colors]` with `ansi` and `brights` arrays): loaded with
+//!   [`ColorScheme::from_wezterm_toml()`][from_wezterm_toml] (requires the
+//!   `toml` feature) and saved with [`ColorScheme::to_wezterm_toml()`]
+//!
+//! - iTerm2 `.itermcolors` plist XML: loaded with
+//!   [`ColorScheme::from_iterm2_plist()`][from_iterm2_plist] (requires the
+//!   `plist` feature) and saved with [`ColorScheme::to_iterm2_plist()`]
+//!
+//! - base16 YAML (`base00` through `base0F`): loaded with
+//!   [`ColorScheme::from_base16_yaml()`][from_base16_yaml] (requires the
+//!   `yaml` feature); this format cannot be saved
+//!
+//! The TOML, property list, and YAML loaders use the
+//! [`toml`](https://crates.io/crates/toml),
+//! [`plist`](https://crates.io/crates/plist), and
+//! [`yaml-rust2`](https://crates.io/crates/yaml-rust2) crates, respectively,
+//! to parse their input.  All of the loaders ignore any settings that are not
+//! colors.
+//!
+//! # Example
+//!
+//! ```
+//! use parse_style::{Color256, RgbColor, Style};
+//! use parse_style::scheme::ColorScheme;
+//!
+//! let scheme = ColorScheme::from_xresources(concat!(
+//!     "#define red #cc6666\n",
+//!     "*.foreground: #c5c8c6\n",
+//!     "*.color1: red\n",
+//!     "*.color9: #d54e53\n",
+//! ))
+//! .unwrap();
+//! assert_eq!(scheme.foreground, Some(RgbColor(0xc5, 0xc8, 0xc6)));
+//! assert_eq!(scheme.background, None);
+//! assert_eq!(scheme.palette.get(Color256::RED), RgbColor(0xcc, 0x66, 0x66));
+//!
+//! let style = Color256::BRIGHT_RED.on(Color256::RED);
+//! assert!(style.contrast_ratio(&scheme.palette, RgbColor(0, 0, 0), RgbColor(0, 0, 0)) < 2.0);
+//! ```
+#![cfg_attr(
+    feature = "toml",
+    doc = "[from_alacritty_toml]: ColorScheme::from_alacritty_toml"
+)]
+#![cfg_attr(not(feature = "toml"), doc = "[from_alacritty_toml]: ColorScheme")]
+#![cfg_attr(
+    feature = "toml",
+    doc = "[from_wezterm_toml]: ColorScheme::from_wezterm_toml"
+)]
+#![cfg_attr(not(feature = "toml"), doc = "[from_wezterm_toml]: ColorScheme")]
+#![cfg_attr(
+    feature = "plist",
+    doc = "[from_iterm2_plist]: ColorScheme::from_iterm2_plist"
+)]
+#![cfg_attr(not(feature = "plist"), doc = "[from_iterm2_plist]: ColorScheme")]
+#![cfg_attr(
+    feature = "yaml",
+    doc = "[from_base16_yaml]: ColorScheme::from_base16_yaml"
+)]
+#![cfg_attr(not(feature = "yaml"), doc = "[from_base16_yaml]: ColorScheme")]
+use crate::palette::Palette;
+use crate::rgbcolor::RgbColor;
+#[cfg(feature = "plist")]
+use crate::util::component_from_f64;
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use crate::util::parse_hex_rgb;
+use crate::util::x11_color;
+use std::collections::HashMap;
+use std::fmt::Write;
+use thiserror::Error;
+#[cfg(feature = "yaml")]
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// The names of the eight basic colors as used by Alacritty
+static COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The base16 color used for each of the sixteen base colors, following the
+/// mapping used by `base16-shell`
+#[cfg(feature = "yaml")]
+static BASE16_ANSI: [&str; 16] = [
+    "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base05", "base03",
+    "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base07",
+];
+
+/// A terminal color scheme: the RGB values of the sixteen base colors, plus
+/// the default foreground and background colors
+///
+/// Colors that a scheme file does not define are taken from
+/// [`Palette::XTERM`], and the foreground and background are `None` if not
+/// defined.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ColorScheme {
+    /// The palette of base colors
+    pub palette: Palette,
+
+    /// The default foreground color
+    pub foreground: Option<RgbColor>,
+
+    /// The default background color
+    pub background: Option<RgbColor>,
+}
+
+impl ColorScheme {
+    /// Create a new `ColorScheme` from a palette, with no foreground or
+    /// background color
+    pub const fn new(palette: Palette) -> ColorScheme {
+        ColorScheme {
+            palette,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    /// Load a color scheme from X resources, as found in an `.Xresources` or
+    /// `.Xdefaults` file
+    ///
+    /// Resources whose names end in `colorN` (for `N` from 0 to 15),
+    /// `foreground`, or `background` are read, regardless of the program or
+    /// class they apply to; e.g., `*.color1`, `*color1`, and `URxvt.color1`
+    /// are all used.  If a resource is given more than once, the last value
+    /// is used.  Values may be `#rgb`, `#rrggbb`, `#rrrgggbbb`,
+    /// `#rrrrggggbbbb`, or `rgb:r/g/b` colors, common X11 color names like
+    /// `red` or `DarkBlue`, or names defined with `#define`.  Other X11 color
+    /// names are skipped, as their values depend on the X server's color
+    /// database.  Comments beginning with `!` and other preprocessor
+    /// directives are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a resource definition, if a color
+    /// value that is not a color name cannot be parsed, or if none of the
+    /// sixteen base colors are defined.
+    pub fn from_xresources(s: &str) -> Result<ColorScheme, LoadSchemeError> {
+        let mut defines = HashMap::new();
+        let mut builder = Builder::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('!') {
+                continue;
+            }
+            if let Some(directive) = line.strip_prefix('#') {
+                let mut words = directive.split_whitespace();
+                if let (Some("define"), Some(name), Some(value)) =
+                    (words.next(), words.next(), words.next())
+                {
+                    defines.insert(name, value);
+                }
+                continue;
+            }
+            let Some((resource, value)) = line.split_once(':') else {
+                return Err(LoadSchemeError::Syntax { lineno: i + 1 });
+            };
+            let resource = resource.trim_end();
+            let value = value.trim();
+            let value = defines.get(value).copied().unwrap_or(value);
+            let Some(key) = resource
+                .rsplit(['*', '.'])
+                .next()
+                .and_then(|name| builder_key(name, None))
+            else {
+                continue;
+            };
+            if let Some(color) = parse_x_color(value) {
+                builder.set(key, color);
+            } else if !is_x_color_name(value) {
+                return Err(LoadSchemeError::Color {
+                    key: resource.to_owned(),
+                    value: value.to_owned(),
+                });
+            }
+        }
+        builder.build()
+    }
+
+    /// Load a color scheme from an Alacritty TOML configuration or theme
+    ///
+    /// The colors are read from the `colors.primary.foreground`,
+    /// `colors.primary.background`, `colors.normal.NAME`, and
+    /// `colors.bright.NAME` keys, where `NAME` is one of `black`, `red`,
+    /// `green`, `yellow`, `blue`, `magenta`, `cyan`, and `white`.  Colors may
+    /// be written as `#rrggbb`, `#rgb`, or `0xrrggbb`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid TOML, if a color value
+    /// cannot be parsed, or if none of the sixteen base colors are defined.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    pub fn from_alacritty_toml(s: &str) -> Result<ColorScheme, LoadSchemeError> {
+        let doc = parse_toml(s)?;
+        let colors = toml_table(&doc, "colors");
+        let mut builder = Builder::default();
+        if let Some(primary) = colors.and_then(|c| toml_table(c, "primary")) {
+            for (key, name) in [
+                (Key::Foreground, "foreground"),
+                (Key::Background, "background"),
+            ] {
+                if let Some(value) = primary.get(name) {
+                    builder.set(key, toml_color(&format!("colors.primary.{name}"), value)?);
+                }
+            }
+        }
+        for (table, offset) in [("normal", 0), ("bright", 8)] {
+            let Some(tbl) = colors.and_then(|c| toml_table(c, table)) else {
+                continue;
+            };
+            for (i, name) in COLOR_NAMES.iter().enumerate() {
+                if let Some(value) = tbl.get(*name) {
+                    let color = toml_color(&format!("colors.{table}.{name}"), value)?;
+                    builder.set(Key::Base(i + offset), color);
+                }
+            }
+        }
+        builder.build()
+    }
+
+    /// Load a color scheme from a WezTerm TOML color scheme file
+    ///
+    /// The colors are read from the `colors.foreground`,
+    /// `colors.background`, `colors.ansi`, and `colors.brights` keys, the
+    /// last two of which are arrays of eight colors each.  Colors may be
+    /// written as `#rrggbb`, `#rgb`, or `0xrrggbb`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid TOML, if a color value
+    /// cannot be parsed, or if none of the sixteen base colors are defined.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    pub fn from_wezterm_toml(s: &str) -> Result<ColorScheme, LoadSchemeError> {
+        let doc = parse_toml(s)?;
+        let mut builder = Builder::default();
+        let Some(colors) = toml_table(&doc, "colors") else {
+            return builder.build();
+        };
+        for (key, name) in [
+            (Key::Foreground, "foreground"),
+            (Key::Background, "background"),
+        ] {
+            if let Some(value) = colors.get(name) {
+                builder.set(key, toml_color(&format!("colors.{name}"), value)?);
+            }
+        }
+        for (name, offset) in [("ansi", 0), ("brights", 8)] {
+            let Some(value) = colors.get(name) else {
+                continue;
+            };
+            let path = format!("colors.{name}");
+            let Some(array) = value.as_array() else {
+                return Err(LoadSchemeError::Color {
+                    key: path,
+                    value: value.to_string(),
+                });
+            };
+            for (i, value) in array.iter().take(8).enumerate() {
+                builder.set(Key::Base(i + offset), toml_color(&path, value)?);
+            }
+        }
+        builder.build()
+    }
+
+    /// Load a color scheme from an iTerm2 `.itermcolors` file (an XML
+    /// property list)
+    ///
+    /// The colors are read from the `Ansi N Color` (for `N` from 0 to 15),
+    /// `Foreground Color`, and `Background Color` entries of the top-level
+    /// dictionary.  Each color is a dictionary with `Red Component`,
+    /// `Green Component`, and `Blue Component` entries ranging from 0.0 to
+    /// 1.0; alpha and color space information is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a valid XML property list with a
+    /// dictionary at the top level, if a color is missing a component, or if
+    /// none of the sixteen base colors are defined.
+    #[cfg(feature = "plist")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "plist")))]
+    pub fn from_iterm2_plist(s: &str) -> Result<ColorScheme, LoadSchemeError> {
+        let value =
+            plist::Value::from_reader_xml(s.as_bytes()).map_err(|e| LoadSchemeError::Format {
+                format: "property list",
+                message: e.to_string(),
+            })?;
+        let Some(entries) = value.as_dictionary() else {
+            return Err(LoadSchemeError::Format {
+                format: "property list",
+                message: String::from("top-level value is not a dictionary"),
+            });
+        };
+        let mut builder = Builder::default();
+        for (name, value) in entries {
+            let key = match name.as_str() {
+                "Foreground Color" => Key::Foreground,
+                "Background Color" => Key::Background,
+                _ => match name
+                    .strip_prefix("Ansi ")
+                    .and_then(|n| n.strip_suffix(" Color"))
+                    .and_then(|n| n.parse::<usize>().ok())
+                {
+                    Some(i) if i < 16 => Key::Base(i),
+                    _ => continue,
+                },
+            };
+            let components = value.as_dictionary();
+            let component = |c: &str| {
+                let value = components?.get(c)?;
+                let x = value.as_real().or_else(|| {
+                    value
+                        .as_signed_integer()
+                        .and_then(|i| i32::try_from(i).ok())
+                        .map(f64::from)
+                })?;
+                Some(component_from_f64(x * 255.0))
+            };
+            let (Some(r), Some(g), Some(b)) = (
+                component("Red Component"),
+                component("Green Component"),
+                component("Blue Component"),
+            ) else {
+                return Err(LoadSchemeError::Missing(name.clone()));
+            };
+            builder.set(key, RgbColor(r, g, b));
+        }
+        builder.build()
+    }
+
+    /// Load a color scheme from a base16 scheme YAML file
+    ///
+    /// Both the original format, in which the `base00` through `base0F` keys
+    /// are at the top level, and the newer format, in which they are nested
+    /// under `palette`, are supported.  Colors are six-digit hex strings with
+    /// or without a leading `#`.  The sixteen base colors are assigned from
+    /// the base16 colors in the same way as by `base16-shell`, with `base05`
+    /// as the foreground and `base00` as the background.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid YAML, if a base16 color
+    /// value cannot be parsed, or if any of the base16 colors used for the
+    /// base colors are missing.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+    pub fn from_base16_yaml(s: &str) -> Result<ColorScheme, LoadSchemeError> {
+        let docs = YamlLoader::load_from_str(s).map_err(|e| LoadSchemeError::Format {
+            format: "YAML",
+            message: e.to_string(),
+        })?;
+        let doc = docs.first().unwrap_or(&Yaml::BadValue);
+        let entries = doc["palette"].as_hash().or_else(|| doc.as_hash());
+        let mut colors = HashMap::new();
+        for (key, value) in entries.into_iter().flatten() {
+            let Some(key) = key.as_str().filter(|k| {
+                k.len() == 6
+                    && k.get(..4)
+                        .is_some_and(|pre| pre.eq_ignore_ascii_case("base"))
+            }) else {
+                continue;
+            };
+            let color = value
+                .as_str()
+                .map(|v| v.strip_prefix('#').unwrap_or(v))
+                .filter(|hex| hex.len() == 6)
+                .and_then(parse_hex_rgb)
+                .ok_or_else(|| LoadSchemeError::Color {
+                    key: key.to_owned(),
+                    value: value
+                        .as_str()
+                        .map_or_else(|| format!("{value:?}"), str::to_owned),
+                })?;
+            colors.insert(key.to_ascii_lowercase(), color);
+        }
+        let get = |key: &str| {
+            colors
+                .get(&key.to_ascii_lowercase())
+                .copied()
+                .ok_or_else(|| LoadSchemeError::Missing(key.to_owned()))
+        };
+        let mut base = [RgbColor(0, 0, 0); 16];
+        for (slot, key) in base.iter_mut().zip(BASE16_ANSI) {
+            *slot = get(key)?;
+        }
+        Ok(ColorScheme {
+            palette: Palette::new(base),
+            foreground: Some(get("base05")?),
+            background: Some(get("base00")?),
+        })
+    }
+
+    /// Export the color scheme as X resources applying to all programs
+    /// (`*.foreground`, `*.background`, and `*.color0` through `*.color15`)
+    pub fn to_xresources(&self) -> String {
+        let mut s = String::new();
+        if let Some(fg) = self.foreground {
+            let _ = writeln!(s, "*.foreground: {fg}");
+        }
+        if let Some(bg) = self.background {
+            let _ = writeln!(s, "*.background: {bg}");
+        }
+        for (i, color) in self.palette.base_colors().into_iter().enumerate() {
+            let _ = writeln!(s, "*.color{i}: {color}");
+        }
+        s
+    }
+
+    /// Export the color scheme as an Alacritty TOML theme
+    pub fn to_alacritty_toml(&self) -> String {
+        let mut s = String::new();
+        if self.foreground.is_some() || self.background.is_some() {
+            s.push_str("[colors.primary]\n");
+            if let Some(fg) = self.foreground {
+                let _ = writeln!(s, "foreground = '{fg}'");
+            }
+            if let Some(bg) = self.background {
+                let _ = writeln!(s, "background = '{bg}'");
+            }
+            s.push('\n');
+        }
+        let base = self.palette.base_colors();
+        let (normal, bright) = base.split_at(8);
+        for (table, colors) in [("normal", normal), ("bright", bright)] {
+            if table == "bright" {
+                s.push('\n');
+            }
+            let _ = writeln!(s, "[colors.{table}]");
+            for (name, color) in COLOR_NAMES.iter().zip(colors) {
+                let _ = writeln!(s, "{name} = '{color}'");
+            }
+        }
+        s
+    }
+
+    /// Export the color scheme as a WezTerm TOML color scheme
+    pub fn to_wezterm_toml(&self) -> String {
+        let mut s = String::from("[colors]\n");
+        if let Some(fg) = self.foreground {
+            let _ = writeln!(s, "foreground = \"{fg}\"");
+        }
+        if let Some(bg) = self.background {
+            let _ = writeln!(s, "background = \"{bg}\"");
+        }
+        let base = self.palette.base_colors();
+        let (normal, bright) = base.split_at(8);
+        for (key, colors) in [("ansi", normal), ("brights", bright)] {
+            let colors = colors
+                .iter()
+                .map(|c| format!("\"{c}\""))
+                .collect::<Vec<_>>();
+            let _ = writeln!(s, "{key} = [{}]", colors.join(", "));
+        }
+        s
+    }
+
+    /// Export the color scheme as an iTerm2 `.itermcolors` file
+    pub fn to_iterm2_plist(&self) -> String {
+        let mut s = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+            "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n",
+            "<dict>\n",
+        ));
+        let base = self.palette.base_colors();
+        let entries = base
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (format!("Ansi {i} Color"), c))
+            .chain(
+                self.foreground
+                    .map(|c| (String::from("Foreground Color"), c)),
+            )
+            .chain(
+                self.background
+                    .map(|c| (String::from("Background Color"), c)),
+            );
+        for (name, RgbColor(r, g, b)) in entries {
+            let _ = writeln!(s, "\t<key>{name}</key>");
+            s.push_str("\t<dict>\n");
+            for (component, value) in [("Blue", b), ("Green", g), ("Red", r)] {
+                let _ = writeln!(s, "\t\t<key>{component} Component</key>");
+                let _ = writeln!(s, "\t\t<real>{}</real>", f64::from(value) / 255.0);
+            }
+            s.push_str("\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>\n");
+            s.push_str("\t</dict>\n");
+        }
+        s.push_str("</dict>\n</plist>\n");
+        s
+    }
+}
+
+impl From<Palette> for ColorScheme {
+    fn from(value: Palette) -> ColorScheme {
+        ColorScheme::new(value)
+    }
+}
+
+/// Error returned when loading a color scheme fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum LoadSchemeError {
+    /// A line of X resources could not be parsed
+    #[error("syntax error on line {lineno}")]
+    Syntax {
+        /// The 1-based number of the line on which the error occurred
+        lineno: usize,
+    },
+
+    /// The input was not valid for the file format being loaded
+    #[error("invalid {format} input: {message}")]
+    Format {
+        /// The name of the file format
+        format: &'static str,
+
+        /// A description of the error
+        message: String,
+    },
+
+    /// A color value could not be parsed
+    #[error("invalid color value for {key:?}: {value:?}")]
+    Color {
+        /// The key or resource that the value was given for
+        key: String,
+
+        /// The invalid value
+        value: String,
+    },
+
+    /// A required entry was missing or incomplete
+    #[error("missing or incomplete color {0:?}")]
+    Missing(
+        /// The name of the entry
+        String,
+    ),
+
+    /// The input did not define any of the sixteen base colors
+    #[error("no base colors defined in color scheme")]
+    NoColors,
+}
+
+/// A color defined by a color scheme file
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Key {
+    Base(usize),
+    Foreground,
+    Background,
+}
+
+/// Map a resource or key name to a `Key`.  `offset` is added to color
+/// indices.
+fn builder_key(name: &str, offset: Option<usize>) -> Option<Key> {
+    if name.eq_ignore_ascii_case("foreground") {
+        Some(Key::Foreground)
+    } else if name.eq_ignore_ascii_case("background") {
+        Some(Key::Background)
+    } else {
+        let i = name
+            .strip_prefix("color")?
+            .parse::<usize>()
+            .ok()?
+            .checked_add(offset.unwrap_or(0))?;
+        (i < 16).then_some(Key::Base(i))
+    }
+}
+
+/// Accumulator for the colors defined by a color scheme file
+#[derive(Clone, Debug, Default)]
+struct Builder {
+    base: [Option<RgbColor>; 16],
+    foreground: Option<RgbColor>,
+    background: Option<RgbColor>,
+}
+
+impl Builder {
+    fn set(&mut self, key: Key, color: RgbColor) {
+        match key {
+            Key::Base(i) => {
+                if let Some(slot) = self.base.get_mut(i) {
+                    *slot = Some(color);
+                }
+            }
+            Key::Foreground => self.foreground = Some(color),
+            Key::Background => self.background = Some(color),
+        }
+    }
+
+    fn build(self) -> Result<ColorScheme, LoadSchemeError> {
+        if self.base.iter().all(Option::is_none) {
+            return Err(LoadSchemeError::NoColors);
+        }
+        let mut base = Palette::XTERM.base_colors();
+        for (slot, color) in base.iter_mut().zip(self.base) {
+            if let Some(color) = color {
+                *slot = color;
+            }
+        }
+        Ok(ColorScheme {
+            palette: Palette::new(base),
+            foreground: self.foreground,
+            background: self.background,
+        })
+    }
+}
+
+/// Parse a `#rrggbb`, `#rgb`, or `0xrrggbb` color
+#[cfg(feature = "toml")]
+fn parse_hex_color(key: &str, value: &str) -> Result<RgbColor, LoadSchemeError> {
+    value
+        .strip_prefix('#')
+        .or_else(|| value.strip_prefix("0x"))
+        .or_else(|| value.strip_prefix("0X"))
+        .and_then(parse_hex_rgb)
+        .ok_or_else(|| LoadSchemeError::Color {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        })
+}
+
+/// Parse an X11 `#rgb`, `#rrggbb`, `#rrrgggbbb`, `#rrrrggggbbbb`, or
+/// `rgb:r/g/b` color or a common X11 color name.  In the `#` forms, the digits
+/// are the high-order bits of each channel, so `#abc` is `#a0b0c0`.  In the
+/// `rgb:` form, each channel may have from one to four hex digits and is
+/// scaled to the full range, so `rgb:a/b/c` is `#aabbcc`.
+fn parse_x_color(value: &str) -> Option<RgbColor> {
+    if let Some(hex) = value.strip_prefix('#') {
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) || !matches!(hex.len(), 3 | 6 | 9 | 12) {
+            return None;
+        }
+        let width = hex.len() / 3;
+        let channel = |i: usize| {
+            let digits = hex.get(i * width..(i * width + width.min(2)))?;
+            let x = u8::from_str_radix(digits, 16).ok()?;
+            Some(if width == 1 { x << 4 } else { x })
+        };
+        Some(RgbColor(channel(0)?, channel(1)?, channel(2)?))
+    } else if let Some(channels) = value.strip_prefix("rgb:") {
+        let mut channels = channels.split('/').map(|c| {
+            if !(1..=4).contains(&c.len()) {
+                return None;
+            }
+            let x = u16::from_str_radix(c, 16).ok()?;
+            let max = (1u32 << (4 * c.len())) - 1;
+            u8::try_from((u32::from(x) * 255 + max / 2) / max).ok()
+        });
+        let (Some(Some(r)), Some(Some(g)), Some(Some(b)), None) = (
+            channels.next(),
+            channels.next(),
+            channels.next(),
+            channels.next(),
+        ) else {
+            return None;
+        };
+        Some(RgbColor(r, g, b))
+    } else {
+        x11_color(value)
+    }
+}
+
+/// Test whether `value` has the form of an X11 color name, i.e., is a
+/// nonempty string of ASCII letters, digits, and spaces
+fn is_x_color_name(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b' ')
+}
+
+/// Parse a TOML document
+#[cfg(feature = "toml")]
+fn parse_toml(s: &str) -> Result<toml::Table, LoadSchemeError> {
+    s.parse::<toml::Table>()
+        .map_err(|e| LoadSchemeError::Format {
+            format: "TOML",
+            message: e.message().to_owned(),
+        })
+}
+
+/// Return the subtable of `table` with the given key, if any
+#[cfg(feature = "toml")]
+fn toml_table<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Table> {
+    table.get(key).and_then(toml::Value::as_table)
+}
+
+/// Parse a TOML value as a `#rrggbb`, `#rgb`, or `0xrrggbb` color
+#[cfg(feature = "toml")]
+fn toml_color(key: &str, value: &toml::Value) -> Result<RgbColor, LoadSchemeError> {
+    match value.as_str() {
+        Some(s) => parse_hex_color(key, s),
+        None => Err(LoadSchemeError::Color {
+            key: key.to_owned(),
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color256::Color256;
+    use rstest::rstest;
+
+    fn sample() -> ColorScheme {
+        let mut base = [RgbColor(0, 0, 0); 16];
+        for (i, slot) in (0u8..).zip(base.iter_mut()) {
+            *slot = RgbColor(i, i * 2, 255 - i);
+        }
+        ColorScheme {
+            palette: Palette::new(base),
+            foreground: Some(RgbColor(0xc5, 0xc8, 0xc6)),
+            background: Some(RgbColor(0x1d, 0x1f, 0x21)),
+        }
+    }
+
+    #[test]
+    fn xresources() {
+        let scheme = ColorScheme::from_xresources(concat!(
+            "! Tomorrow Night\n",
+            "#define t_background #1d1f21\n",
+            "#include \"other\"\n",
+            "*.background: t_background\n",
+            "URxvt*foreground:\t#c5c8c6\n",
+            "*color0: #000\n",
+            "*color1: dark red\n",
+            "*color2: #8a0\n",
+            "*color3: gold3\n",
+            "XTerm.vt100.color8: rgb:80/8/ffff\n",
+            "*.color15: #ffffff\n",
+            "*.color15: #fefefe\n",
+            "*.color16: #123456\n",
+            "*.cursorColor: #aeafad\n",
+            "URxvt.font: xft:Monospace:size=10\n",
+        ))
+        .unwrap();
+        assert_eq!(scheme.background, Some(RgbColor(0x1d, 0x1f, 0x21)));
+        assert_eq!(scheme.foreground, Some(RgbColor(0xc5, 0xc8, 0xc6)));
+        assert_eq!(scheme.palette.get(Color256(0)), RgbColor(0, 0, 0));
+        assert_eq!(scheme.palette.get(Color256(1)), RgbColor(0x8b, 0, 0));
+        assert_eq!(scheme.palette.get(Color256(2)), RgbColor(0x80, 0xa0, 0));
+        assert_eq!(
+            scheme.palette.get(Color256(3)),
+            Palette::XTERM.get(Color256(3))
+        );
+        assert_eq!(scheme.palette.get(Color256(8)), RgbColor(0x80, 0x88, 0xff));
+        assert_eq!(scheme.palette.get(Color256(15)), RgbColor(0xfe, 0xfe, 0xfe));
+    }
+
+    #[rstest]
+    #[case("*.color1 #ff0000", LoadSchemeError::Syntax { lineno: 1 })]
+    #[case(
+        "\n*.color1: #ff00",
+        LoadSchemeError::Color { key: "*.color1".into(), value: "#ff00".into() }
+    )]
+    #[case(
+        "*.color1:",
+        LoadSchemeError::Color { key: "*.color1".into(), value: String::new() }
+    )]
+    #[case(
+        "*.color1: rgb:ff/0",
+        LoadSchemeError::Color { key: "*.color1".into(), value: "rgb:ff/0".into() }
+    )]
+    #[case("*.foreground: #ffffff", LoadSchemeError::NoColors)]
+    fn xresources_error(#[case] s: &str, #[case] err: LoadSchemeError) {
+        assert_eq!(ColorScheme::from_xresources(s), Err(err));
+    }
+
+    #[test]
+    fn xresources_roundtrip() {
+        let scheme = sample();
+        assert_eq!(
+            ColorScheme::from_xresources(&scheme.to_xresources()),
+            Ok(scheme)
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn alacritty() {
+        let scheme = ColorScheme::from_alacritty_toml(concat!(
+            "# Colors (Tomorrow Night)\n",
+            "[general]\n",
+            "live_config_reload = true\n",
+            "\n",
+            "[colors.primary]\n",
+            "background = '#1d1f21' # comment\n",
+            "foreground = \"0xc5c8c6\"\n",
+            "\n",
+            "[colors]\n",
+            "normal = { black = '#000000', red = \"#cc6666\" }\n",
+            "bright.white = '#fff'\n",
+            "\n",
+            "[colors.cursor]\n",
+            "text = 'CellBackground'\n",
+            "\n",
+            "[[hints.enabled]]\n",
+            "regex = \"\"\"[a-z]+\"\"\"\n",
+            "mouse = { enabled = true, mods = \"None\" }\n",
+        ))
+        .unwrap();
+        assert_eq!(scheme.background, Some(RgbColor(0x1d, 0x1f, 0x21)));
+        assert_eq!(scheme.foreground, Some(RgbColor(0xc5, 0xc8, 0xc6)));
+        assert_eq!(
+            scheme.palette.get(Color256::RED),
+            RgbColor(0xcc, 0x66, 0x66)
+        );
+        assert_eq!(
+            scheme.palette.get(Color256::BRIGHT_WHITE),
+            RgbColor(0xff, 0xff, 0xff)
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[rstest]
+    #[case("[colors.normal]\nred = '#cc6666")]
+    #[case("[colors.normal\nred = '#cc6666'")]
+    #[case("[colors.normal]\nred '#cc6666'")]
+    #[case("[colors.normal]\nred = '#cc6666' blue")]
+    fn alacritty_syntax_error(#[case] s: &str) {
+        assert!(matches!(
+            ColorScheme::from_alacritty_toml(s),
+            Err(LoadSchemeError::Format { format: "TOML", .. })
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[rstest]
+    #[case(
+        "[colors.normal]\nred = 'red'",
+        LoadSchemeError::Color { key: "colors.normal.red".into(), value: "red".into() }
+    )]
+    #[case(
+        "[colors.primary]\nforeground = 0xffffff",
+        LoadSchemeError::Color { key: "colors.primary.foreground".into(), value: "16777215".into() }
+    )]
+    #[case("[colors.primary]\nforeground = '#ffffff'", LoadSchemeError::NoColors)]
+    fn alacritty_error(#[case] s: &str, #[case] err: LoadSchemeError) {
+        assert_eq!(ColorScheme::from_alacritty_toml(s), Err(err));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn alacritty_roundtrip() {
+        let scheme = sample();
+        let toml = scheme.to_alacritty_toml();
+        assert!(toml.starts_with("[colors.primary]\nforeground = '#c5c8c6'\n"));
+        assert_eq!(ColorScheme::from_alacritty_toml(&toml), Ok(scheme));
+        let scheme = ColorScheme::new(sample().palette);
+        let toml = scheme.to_alacritty_toml();
+        assert!(toml.starts_with("[colors.normal]\nblack = '#0000ff'\n"));
+        assert_eq!(ColorScheme::from_alacritty_toml(&toml), Ok(scheme));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn wezterm() {
+        let scheme = ColorScheme::from_wezterm_toml(concat!(
+            "[colors]\n",
+            "foreground = \"#c5c8c6\"\n",
+            "ansi = [\n",
+            "    \"#000000\", \"#cc6666\", \"#b5bd68\", \"#f0c674\",\n",
+            "    \"#81a2be\", \"#b294bb\", \"#8abeb7\", \"#c5c8c6\", # comment\n",
+            "]\n",
+            "brights = [\"#666666\"]\n",
+            "indexed = { 136 = \"#af8700\" }\n",
+            "\n",
+            "[metadata]\n",
+            "name = \"Tomorrow Night\"\n",
+            "aliases = []\n",
+        ))
+        .unwrap();
+        assert_eq!(scheme.foreground, Some(RgbColor(0xc5, 0xc8, 0xc6)));
+        assert_eq!(scheme.background, None);
+        assert_eq!(scheme.palette.get(Color256(4)), RgbColor(0x81, 0xa2, 0xbe));
+        assert_eq!(scheme.palette.get(Color256(8)), RgbColor(0x66, 0x66, 0x66));
+        assert_eq!(
+            scheme.palette.get(Color256(9)),
+            Palette::XTERM.get(Color256(9))
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[rstest]
+    #[case(
+        "[colors]\nansi = '#000000'",
+        LoadSchemeError::Color { key: "colors.ansi".into(), value: "\"#000000\"".into() }
+    )]
+    #[case(
+        "[colors]\nansi = ['black']",
+        LoadSchemeError::Color { key: "colors.ansi".into(), value: "black".into() }
+    )]
+    #[case("[metadata]\nname = 'empty'", LoadSchemeError::NoColors)]
+    fn wezterm_error(#[case] s: &str, #[case] err: LoadSchemeError) {
+        assert_eq!(ColorScheme::from_wezterm_toml(s), Err(err));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn wezterm_roundtrip() {
+        let scheme = sample();
+        assert_eq!(
+            ColorScheme::from_wezterm_toml(&scheme.to_wezterm_toml()),
+            Ok(scheme)
+        );
+    }
+
+    #[cfg(feature = "plist")]
+    #[test]
+    fn iterm2() {
+        let scheme = ColorScheme::from_iterm2_plist(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+            "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+            "<plist version=\"1.0\">\n",
+            "<dict>\n",
+            "  <!-- red -->\n",
+            "  <key>Ansi 1 Color</key>\n",
+            "  <dict>\n",
+            "    <key>Alpha Component</key><real>1</real>\n",
+            "    <key>Blue Component</key><real>0.4</real>\n",
+            "    <key>Color Space</key><string>sRGB</string>\n",
+            "    <key>Green Component</key><real>0.4</real>\n",
+            "    <key>Red Component</key><integer>1</integer>\n",
+            "  </dict>\n",
+            "  <key>Background Color</key>\n",
+            "  <dict>\n",
+            "    <key>Blue Component</key><real>0.12941</real>\n",
+            "    <key>Green Component</key><real>0.12157</real>\n",
+            "    <key>Red Component</key><real>0.11373</real>\n",
+            "  </dict>\n",
+            "  <key>Ansi 16 Color</key><dict></dict>\n",
+            "  <key>Tags</key><array><string>a &amp; b</string><true/></array>\n",
+            "</dict>\n",
+            "</plist>\n",
+        ))
+        .unwrap();
+        assert_eq!(
+            scheme.palette.get(Color256::RED),
+            RgbColor(0xff, 0x66, 0x66)
+        );
+        assert_eq!(scheme.background, Some(RgbColor(0x1d, 0x1f, 0x21)));
+        assert_eq!(scheme.foreground, None);
+    }
+
+    #[cfg(feature = "plist")]
+    #[rstest]
+    #[case(
+        concat!(
+            "<plist><dict>",
+            "<key>Ansi 1 Color</key><dict></dict>",
+            "</dict></plist>",
+        ),
+        LoadSchemeError::Missing("Ansi 1 Color".into())
+    )]
+    #[case(
+        concat!(
+            "<plist><dict>",
+            "<key>Ansi 1 Color</key><string>red</string>",
+            "</dict></plist>",
+        ),
+        LoadSchemeError::Missing("Ansi 1 Color".into())
+    )]
+    #[case("<plist><dict></dict></plist>", LoadSchemeError::NoColors)]
+    fn iterm2_error(#[case] s: &str, #[case] err: LoadSchemeError) {
+        assert_eq!(ColorScheme::from_iterm2_plist(s), Err(err));
+    }
+
+    #[cfg(feature = "plist")]
+    #[rstest]
+    #[case("<plist>\n<dict><key>Ansi 1 Color</key>\n<dict>")]
+    #[case("<plist><array></array></plist>")]
+    #[case("not xml")]
+    fn iterm2_format_error(#[case] s: &str) {
+        assert!(matches!(
+            ColorScheme::from_iterm2_plist(s),
+            Err(LoadSchemeError::Format {
+                format: "property list",
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "plist")]
+    #[test]
+    fn iterm2_roundtrip() {
+        let scheme = sample();
+        assert_eq!(
+            ColorScheme::from_iterm2_plist(&scheme.to_iterm2_plist()),
+            Ok(scheme)
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[rstest]
+    #[case(concat!(
+        "scheme: \"Default Dark\"\n",
+        "author: \"Chris Kempson\"\n",
+        "base00: \"181818\"\n",
+        "base01: \"282828\"\n",
+        "base02: \"383838\"\n",
+        "base03: \"585858\"\n",
+        "base04: \"b8b8b8\"\n",
+        "base05: \"d8d8d8\"\n",
+        "base06: \"e8e8e8\"\n",
+        "base07: \"f8f8f8\"\n",
+        "base08: \"ab4642\"\n",
+        "base09: \"dc9656\"\n",
+        "base0A: \"f7ca88\"\n",
+        "base0B: \"a1b56c\"\n",
+        "base0C: \"86c1b9\"\n",
+        "base0D: \"7cafc2\"\n",
+        "base0E: \"ba8baf\"\n",
+        "base0F: \"a16946\"\n",
+    ))]
+    #[case(concat!(
+        "system: \"base16\"\n",
+        "name: \"Default Dark\"\n",
+        "variant: \"dark\"\n",
+        "palette:\n",
+        "  base00: \"#181818\" # background\n",
+        "  base03: '#585858'\n",
+        "  base05: d8d8d8\n",
+        "  base07: \"#f8f8f8\"\n",
+        "  base08: \"#ab4642\"\n",
+        "  base0a: \"#f7ca88\"\n",
+        "  base0b: \"#a1b56c\"\n",
+        "  base0c: \"#86c1b9\"\n",
+        "  base0d: \"#7cafc2\"\n",
+        "  base0e: \"#ba8baf\"\n",
+    ))]
+    fn base16(#[case] s: &str) {
+        let scheme = ColorScheme::from_base16_yaml(s).unwrap();
+        assert_eq!(scheme.background, Some(RgbColor(0x18, 0x18, 0x18)));
+        assert_eq!(scheme.foreground, Some(RgbColor(0xd8, 0xd8, 0xd8)));
+        assert_eq!(scheme.palette.get(Color256(0)), RgbColor(0x18, 0x18, 0x18));
+        assert_eq!(scheme.palette.get(Color256(1)), RgbColor(0xab, 0x46, 0x42));
+        assert_eq!(scheme.palette.get(Color256(3)), RgbColor(0xf7, 0xca, 0x88));
+        assert_eq!(scheme.palette.get(Color256(8)), RgbColor(0x58, 0x58, 0x58));
+        assert_eq!(scheme.palette.get(Color256(9)), RgbColor(0xab, 0x46, 0x42));
+        assert_eq!(scheme.palette.get(Color256(15)), RgbColor(0xf8, 0xf8, 0xf8));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[rstest]
+    #[case(
+        "base00: \"18181\"",
+        LoadSchemeError::Color { key: "base00".into(), value: "18181".into() }
+    )]
+    #[case("base00: \"181818\"", LoadSchemeError::Missing("base08".into()))]
+    #[case("- base00", LoadSchemeError::Missing("base00".into()))]
+    fn base16_error(#[case] s: &str, #[case] err: LoadSchemeError) {
+        assert_eq!(ColorScheme::from_base16_yaml(s), Err(err));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn base16_format_error() {
+        assert!(matches!(
+            ColorScheme::from_base16_yaml("base00: [\"181818\""),
+            Err(LoadSchemeError::Format { format: "YAML", .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("#123456", Some(RgbColor(0x12, 0x34, 0x56)))]
+    #[case("#abc", Some(RgbColor(0xa0, 0xb0, 0xc0)))]
+    #[case("#123456789", Some(RgbColor(0x12, 0x45, 0x78)))]
+    #[case("#ffff80000000", Some(RgbColor(0xff, 0x80, 0x00)))]
+    #[case("rgb:12/34/56", Some(RgbColor(0x12, 0x34, 0x56)))]
+    #[case("rgb:f/0/8", Some(RgbColor(0xff, 0x00, 0x88)))]
+    #[case("rgb:ffff/8000/0", Some(RgbColor(0xff, 0x80, 0x00)))]
+    #[case("rgb:12/34", None)]
+    #[case("rgb:12/34/56/78", None)]
+    #[case("rgb:12345/0/0", None)]
+    #[case("#12345", None)]
+    #[case("#1234", None)]
+    #[case("#12345g", None)]
+    #[case("red", Some(RgbColor(0xff, 0x00, 0x00)))]
+    #[case("Dark Blue", Some(RgbColor(0x00, 0x00, 0x8b)))]
+    #[case("gold3", None)]
+    fn x_color(#[case] s: &str, #[case] rgb: Option<RgbColor>) {
+        assert_eq!(parse_x_color(s), rgb);
+    }
+}
@@ -26,6 +26,8 @@ pub mod style {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
         crate::Style::deserialize(deserializer).map(Style::from)
     }
+
+    with_variants!(Style);
 }
 
 /// A module for use via `#[serde(with)]` for serializing & deserializing
@@ -58,6 +60,8 @@ pub mod color {
         let c = crate::Color::deserialize(deserializer)?;
         Color::try_from(c).map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str("default"), &r##"a color word or a string of the form "color(INT)", "rgb(INT,INT,INT)", or "#xxxxxx""##))
     }
+
+    with_variants!(Color);
 }
 
 /// A module for use via `#[serde(with)]` for serializing & deserializing
@@ -90,6 +94,8 @@ pub mod ansi256color {
     ) -> Result<Ansi256Color, D::Error> {
         crate::Color256::deserialize(deserializer).map(Ansi256Color::from)
     }
+
+    with_variants!(Ansi256Color);
 }
 
 /// A module for use via `#[serde(with)]` for serializing & deserializing
@@ -117,4 +123,6 @@ pub mod rgb_color {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RgbColor, D::Error> {
         crate::RgbColor::deserialize(deserializer).map(RgbColor::from)
     }
+
+    with_variants!(RgbColor);
 }
@@ -0,0 +1,36 @@
+//! (De)serializing [`colored`] types
+use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`colored::Color`] values as color words and RGB codes.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::colored::color")]
+///     color: colored::Color,
+/// }
+/// ```
+///
+/// Note that attempting to deserialize a string of the form `"default"` with
+/// this module will produce an "invalid value" error.
+pub mod color {
+    use super::*;
+    use colored::Color;
+    use serde::de::Error;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Color::from(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let c = crate::Color::deserialize(deserializer)?;
+        Color::try_from(c).map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str("default"), &r##"a color word or a string of the form "color(INT)", "rgb(INT,INT,INT)", or "#xxxxxx""##))
+    }
+
+    with_variants!(Color);
+}
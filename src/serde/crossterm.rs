@@ -31,6 +31,8 @@ pub mod content_style {
     ) -> Result<ContentStyle, D::Error> {
         crate::Style::deserialize(deserializer).map(ContentStyle::from)
     }
+
+    with_variants!(ContentStyle);
 }
 
 /// A module for use via `#[serde(with)]` for serializing & deserializing
@@ -58,4 +60,6 @@ pub mod color {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
         crate::Color::deserialize(deserializer).map(Color::from)
     }
+
+    with_variants!(Color);
 }
@@ -11,15 +11,176 @@
 //! `"red bold blink2"` as an `anstyle::Style` will result in a style with a red
 //! foreground and only a bold effect, as anstyle does not support rapid
 //! blinking.
+//!
+//! Each of these submodules in turn contains `option`, `vec`, and `map`
+//! submodules for (de)serializing `Option`s, `Vec`s, and maps (such as
+//! `HashMap` and `BTreeMap`) of the foreign type in the same way:
+//!
+//! ```
+//! # #[cfg(feature = "anstyle")] {
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Config {
+//!     #[serde(default, with = "parse_style::serde::anstyle::style::option")]
+//!     prompt: Option<anstyle::Style>,
+//!     #[serde(with = "parse_style::serde::anstyle::color::vec")]
+//!     rainbow: Vec<anstyle::Color>,
+//!     #[serde(with = "parse_style::serde::anstyle::style::map")]
+//!     levels: HashMap<String, anstyle::Style>,
+//! }
+//! # }
+//! ```
+//!
+//! Note that, as with `Option` fields in general, an `option` field must be
+//! marked `#[serde(default)]` in order to be omittable.
+
+#[cfg(any(
+    feature = "anstyle",
+    feature = "colored",
+    feature = "console",
+    feature = "crossterm",
+    feature = "nu-ansi-term",
+    feature = "owo-colors",
+    feature = "ratatui",
+    feature = "termcolor",
+    feature = "yansi",
+))]
+mod variants;
+
+/// Generate `option`, `vec`, and `map` submodules for the `#[serde(with)]`
+/// module in which the macro is invoked, where `$t` is the type handled by
+/// that module's `serialize()` and `deserialize()` functions
+#[cfg(any(
+    feature = "anstyle",
+    feature = "colored",
+    feature = "console",
+    feature = "crossterm",
+    feature = "nu-ansi-term",
+    feature = "owo-colors",
+    feature = "ratatui",
+    feature = "termcolor",
+    feature = "yansi",
+))]
+macro_rules! with_variants {
+    ($t:ty) => {
+        struct This;
+
+        impl $crate::serde::variants::WithModule for This {
+            type Value = $t;
+
+            fn serialize<S: ::serde::Serializer>(
+                value: &$t,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize(value, serializer)
+            }
+
+            fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$t, D::Error> {
+                deserialize(deserializer)
+            }
+        }
+
+        /// A module for use via `#[serde(with)]` for serializing &
+        /// deserializing `Option`s of the parent module's type
+        pub mod option {
+            use super::*;
+
+            pub fn serialize<S: ::serde::Serializer>(
+                value: &Option<$t>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde::variants::serialize_option::<This, S>(value, serializer)
+            }
+
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$t>, D::Error> {
+                $crate::serde::variants::deserialize_option::<This, D>(deserializer)
+            }
+        }
+
+        /// A module for use via `#[serde(with)]` for serializing &
+        /// deserializing `Vec`s of the parent module's type
+        pub mod vec {
+            use super::*;
+
+            pub fn serialize<S: ::serde::Serializer>(
+                value: &[$t],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde::variants::serialize_vec::<This, S>(value, serializer)
+            }
+
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<$t>, D::Error> {
+                $crate::serde::variants::deserialize_vec::<This, D>(deserializer)
+            }
+        }
+
+        /// A module for use via `#[serde(with)]` for serializing &
+        /// deserializing maps (such as `HashMap` and `BTreeMap`) whose values
+        /// are of the parent module's type
+        pub mod map {
+            use super::*;
+
+            pub fn serialize<'a, K, M, S>(value: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                &'a M: IntoIterator<Item = (&'a K, &'a $t)>,
+                K: ::serde::Serialize + 'a,
+                S: ::serde::Serializer,
+            {
+                $crate::serde::variants::serialize_map::<This, K, _, S>(value, serializer)
+            }
+
+            pub fn deserialize<'de, K, M, D>(deserializer: D) -> Result<M, D::Error>
+            where
+                K: ::serde::Deserialize<'de>,
+                M: Default + Extend<(K, $t)>,
+                D: ::serde::Deserializer<'de>,
+            {
+                $crate::serde::variants::deserialize_map::<This, K, M, D>(deserializer)
+            }
+        }
+    };
+}
 
 #[cfg(feature = "anstyle")]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "anstyle", feature = "serde"))))]
 pub mod anstyle;
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "colored", feature = "serde"))))]
+pub mod colored;
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "console", feature = "serde"))))]
+pub mod console;
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "crossterm", feature = "serde"))))]
 pub mod crossterm;
 
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "nu-ansi-term", feature = "serde"))))]
+pub mod nu_ansi_term;
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "owo-colors", feature = "serde"))))]
+pub mod owo_colors;
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "ratatui", feature = "serde"))))]
 pub mod ratatui;
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "termcolor", feature = "serde"))))]
+pub mod termcolor;
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "yansi", feature = "serde"))))]
+pub mod yansi;
@@ -0,0 +1,60 @@
+//! (De)serializing [`nu_ansi_term`] types
+use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`nu_ansi_term::Style`] values as style strings.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::nu_ansi_term::style")]
+///     style: nu_ansi_term::Style,
+/// }
+/// ```
+pub mod style {
+    use super::*;
+    use nu_ansi_term::Style;
+
+    pub fn serialize<S: Serializer>(style: &Style, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Style::from(*style).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+        crate::Style::deserialize(deserializer).map(Style::from)
+    }
+
+    with_variants!(Style);
+}
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`nu_ansi_term::Color`] values as color words and RGB codes.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::nu_ansi_term::color")]
+///     color: nu_ansi_term::Color,
+/// }
+/// ```
+pub mod color {
+    use super::*;
+    use nu_ansi_term::Color;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Color::from(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        crate::Color::deserialize(deserializer).map(Color::from)
+    }
+
+    with_variants!(Color);
+}
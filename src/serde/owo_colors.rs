@@ -0,0 +1,60 @@
+//! (De)serializing [`owo_colors`] types
+use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`owo_colors::Style`] values as style strings.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::owo_colors::style")]
+///     style: owo_colors::Style,
+/// }
+/// ```
+pub mod style {
+    use super::*;
+    use owo_colors::Style;
+
+    pub fn serialize<S: Serializer>(style: &Style, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Style::from(*style).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+        crate::Style::deserialize(deserializer).map(Style::from)
+    }
+
+    with_variants!(Style);
+}
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`owo_colors::DynColors`] values as color words and RGB codes.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::owo_colors::dyn_colors")]
+///     color: owo_colors::DynColors,
+/// }
+/// ```
+pub mod dyn_colors {
+    use super::*;
+    use owo_colors::DynColors;
+
+    pub fn serialize<S: Serializer>(color: &DynColors, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Color::from(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DynColors, D::Error> {
+        crate::Color::deserialize(deserializer).map(DynColors::from)
+    }
+
+    with_variants!(DynColors);
+}
@@ -26,6 +26,8 @@ pub mod style {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
         crate::Style::deserialize(deserializer).map(Style::from)
     }
+
+    with_variants!(Style);
 }
 
 /// A module for use via `#[serde(with)]` for serializing & deserializing
@@ -53,4 +55,6 @@ pub mod color {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
         crate::Color::deserialize(deserializer).map(Color::from)
     }
+
+    with_variants!(Color);
 }
@@ -0,0 +1,81 @@
+//! (De)serializing [`termcolor`] types
+//!
+//! # Data Loss
+//!
+//! Values are (de)serialized by way of [`Style`][crate::Style], so
+//! round-tripping through this module is subject to the same losses as the
+//! conversions between `Style` and [`termcolor::ColorSpec`]:
+//!
+//! - A `ColorSpec`'s "reset" flag is not serialized.
+//!
+//! - An intense `ColorSpec` whose foreground and background are not named
+//!   colors is serialized without its intensity.
+//!
+//! - When deserializing, attributes that `ColorSpec` does not support (blink,
+//!   reverse, conceal, double underline, frame, encircle, and overline) are
+//!   discarded, default colors become `None`, and a bright foreground or
+//!   background color makes the whole `ColorSpec` intense.
+use serde::{Deserialize, Serialize, de::Deserializer, ser::Serializer};
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`termcolor::ColorSpec`] values as style strings.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::termcolor::color_spec")]
+///     spec: termcolor::ColorSpec,
+/// }
+/// ```
+pub mod color_spec {
+    use super::*;
+    use termcolor::ColorSpec;
+
+    pub fn serialize<S: Serializer>(spec: &ColorSpec, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Style::from(spec).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ColorSpec, D::Error> {
+        crate::Style::deserialize(deserializer).map(ColorSpec::from)
+    }
+
+    with_variants!(ColorSpec);
+}
+
+/// A module for use via `#[serde(with)]` for serializing & deserializing
+/// [`termcolor::Color`] values as color words and RGB codes.
+///
+/// Use it like so:
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct MyStruct {
+///     #[serde(with = "parse_style::serde::termcolor::color")]
+///     color: termcolor::Color,
+/// }
+/// ```
+///
+/// Note that attempting to deserialize a string of the form `"default"` with
+/// this module will produce an "invalid value" error.
+pub mod color {
+    use super::*;
+    use serde::de::Error;
+    use termcolor::Color;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Color::from(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let c = crate::Color::deserialize(deserializer)?;
+        Color::try_from(c).map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str("default"), &r##"a color word or a string of the form "color(INT)", "rgb(INT,INT,INT)", or "#xxxxxx""##))
+    }
+
+    with_variants!(Color);
+}
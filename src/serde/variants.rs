@@ -0,0 +1,163 @@
+//! Support code for the `option`, `vec`, and `map` variants of the
+//! `#[serde(with)]` modules
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A `#[serde(with)]` module's `serialize()` & `deserialize()` functions,
+/// bundled into a trait so that they can be applied to container elements
+pub(crate) trait WithModule {
+    type Value;
+
+    fn serialize<S: Serializer>(value: &Self::Value, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::Value, D::Error>;
+}
+
+/// Wrapper for serializing a borrowed value using a `WithModule`
+pub(crate) struct SerializeWith<'a, M: WithModule>(pub(crate) &'a M::Value);
+
+impl<M: WithModule> Serialize for SerializeWith<'_, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        M::serialize(self.0, serializer)
+    }
+}
+
+/// Wrapper for deserializing a value using a `WithModule`
+pub(crate) struct DeserializeWith<M: WithModule>(pub(crate) M::Value);
+
+impl<'de, M: WithModule> Deserialize<'de> for DeserializeWith<M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        M::deserialize(deserializer).map(DeserializeWith)
+    }
+}
+
+pub(crate) fn serialize_option<M: WithModule, S: Serializer>(
+    value: &Option<M::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.as_ref().map(SerializeWith::<M>).serialize(serializer)
+}
+
+pub(crate) fn deserialize_option<'de, M: WithModule, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<M::Value>, D::Error> {
+    Option::<DeserializeWith<M>>::deserialize(deserializer).map(|opt| opt.map(|w| w.0))
+}
+
+pub(crate) fn serialize_vec<M: WithModule, S: Serializer>(
+    value: &[M::Value],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(value.iter().map(SerializeWith::<M>))
+}
+
+pub(crate) fn deserialize_vec<'de, M: WithModule, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<M::Value>, D::Error> {
+    Vec::<DeserializeWith<M>>::deserialize(deserializer)
+        .map(|v| v.into_iter().map(|w| w.0).collect())
+}
+
+pub(crate) fn serialize_map<'a, M, K, I, S>(value: I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    M: WithModule + 'a,
+    K: Serialize + 'a,
+    I: IntoIterator<Item = (&'a K, &'a M::Value)>,
+    S: Serializer,
+{
+    serializer.collect_map(value.into_iter().map(|(k, v)| (k, SerializeWith::<M>(v))))
+}
+
+pub(crate) fn deserialize_map<'de, M, K, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+    M: WithModule,
+    K: Deserialize<'de>,
+    C: Default + Extend<(K, M::Value)>,
+    D: Deserializer<'de>,
+{
+    struct MapVisitor<M, K, C>(PhantomData<(M, K, C)>);
+
+    impl<'de, M, K, C> Visitor<'de> for MapVisitor<M, K, C>
+    where
+        M: WithModule,
+        K: Deserialize<'de>,
+        C: Default + Extend<(K, M::Value)>,
+    {
+        type Value = C;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+            let mut collection = C::default();
+            while let Some((key, value)) = map.next_entry::<K, DeserializeWith<M>>()? {
+                collection.extend(std::iter::once((key, value.0)));
+            }
+            Ok(collection)
+        }
+    }
+
+    deserializer.deserialize_map(MapVisitor::<M, K, C>(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color256, Style};
+    use serde::de::IntoDeserializer;
+    use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+    use std::collections::BTreeMap;
+
+    struct Bold;
+
+    impl WithModule for Bold {
+        type Value = Style;
+
+        fn serialize<S: Serializer>(value: &Style, serializer: S) -> Result<S::Ok, S::Error> {
+            value.serialize(serializer)
+        }
+
+        fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+            Style::deserialize(deserializer).map(Style::bold)
+        }
+    }
+
+    #[test]
+    fn option_none() {
+        let de = ().into_deserializer();
+        let r: Result<_, Error> = deserialize_option::<Bold, _>(de);
+        assert_eq!(r, Ok(None));
+    }
+
+    #[test]
+    fn vec() {
+        let de = SeqDeserializer::<_, Error>::new(["red", "not bold"].into_iter());
+        assert_eq!(
+            deserialize_vec::<Bold, _>(de),
+            Ok(vec![Style::from(Color256::RED).bold(), Style::new().bold()])
+        );
+    }
+
+    #[test]
+    fn map() {
+        let de =
+            MapDeserializer::<_, Error>::new([("error", "red"), ("warn", "yellow")].into_iter());
+        let r: Result<BTreeMap<String, Style>, _> = deserialize_map::<Bold, _, _, _>(de);
+        assert_eq!(
+            r,
+            Ok(BTreeMap::from([
+                ("error".into(), Style::from(Color256::RED).bold()),
+                ("warn".into(), Style::from(Color256::YELLOW).bold()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn bad_element() {
+        let de = SeqDeserializer::<_, Error>::new(["red", "on"].into_iter());
+        assert!(deserialize_vec::<Bold, _>(de).is_err());
+    }
+}
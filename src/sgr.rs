@@ -0,0 +1,266 @@
+use crate::attributes::Attribute;
+use crate::color::Color;
+use crate::color256::Color256;
+use crate::rgbcolor::RgbColor;
+use crate::style::Style;
+
+/// Parse a semicolon-separated list of SGR (Select Graphic Rendition)
+/// parameters — the part of an `ESC [ ... m` escape sequence between the `[`
+/// and the `m` — into a [`Style`].
+///
+/// Empty parameters are treated as 0.  Unrecognized or malformed parameters
+/// are ignored.
+#[cfg(any(feature = "owo-colors", feature = "yansi"))]
+pub(crate) fn parse_sgr_params(params: &str) -> Style {
+    apply_sgr_params(Style::new(), split_sgr_params(params))
+}
+
+/// Split a semicolon-separated list of SGR parameters into values suitable
+/// for passing to [`apply_sgr_params()`].  Empty parameters are treated as 0,
+/// and malformed parameters are returned as `None`.
+pub(crate) fn split_sgr_params(params: &str) -> impl Iterator<Item = Option<u16>> + '_ {
+    params.split(';').map(|p| {
+        if p.is_empty() {
+            Some(0)
+        } else {
+            p.parse::<u16>().ok()
+        }
+    })
+}
+
+/// Apply a sequence of SGR parameters to `style`.  `None` values represent
+/// malformed parameters and are ignored.
+pub(crate) fn apply_sgr_params<I>(mut style: Style, params: I) -> Style
+where
+    I: IntoIterator<Item = Option<u16>>,
+{
+    let mut params = params.into_iter();
+    while let Some(p) = params.next() {
+        let Some(p) = p else {
+            continue;
+        };
+        style = match p {
+            0 => Style::new(),
+            1 => style.bold(),
+            2 => style.dim(),
+            3 => style.italic(),
+            4 => style.underline(),
+            5 => style.blink(),
+            6 => style.blink2(),
+            7 => style.reverse(),
+            8 => style.conceal(),
+            9 => style.strike(),
+            21 => style.underline2(),
+            22 => style.not_bold().not_dim(),
+            23 => style.not_italic(),
+            24 => style.not_underline().not_underline2(),
+            25 => style.not_blink().not_blink2(),
+            27 => style.not_reverse(),
+            28 => style.not_conceal(),
+            29 => style.not_strike(),
+            30..=37 => style.foreground(Some(basic_color(p - 30))),
+            38 => match extended_color(&mut params) {
+                Some(c) => style.foreground(Some(c)),
+                None => style,
+            },
+            39 => style.foreground(Some(Color::Default)),
+            40..=47 => style.background(Some(basic_color(p - 40))),
+            48 => match extended_color(&mut params) {
+                Some(c) => style.background(Some(c)),
+                None => style,
+            },
+            49 => style.background(Some(Color::Default)),
+            51 => style.frame(),
+            52 => style.encircle(),
+            53 => style.overline(),
+            54 => style.disable(Attribute::Frame | Attribute::Encircle),
+            55 => style.not_overline(),
+            90..=97 => style.foreground(Some(basic_color(p - 90 + 8))),
+            100..=107 => style.background(Some(basic_color(p - 100 + 8))),
+            _ => style,
+        };
+    }
+    style
+}
+
+fn basic_color(index: u16) -> Color {
+    let index = u8::try_from(index).expect("basic color index should fit in a u8");
+    Color::Color256(Color256(index))
+}
+
+/// Parse the parameters following a 38 or 48 parameter
+fn extended_color<I: Iterator<Item = Option<u16>>>(params: &mut I) -> Option<Color> {
+    let mut next_u8 = || u8::try_from(params.next()??).ok();
+    match next_u8()? {
+        5 => Some(Color::Color256(Color256(next_u8()?))),
+        2 => {
+            let r = next_u8()?;
+            let g = next_u8()?;
+            let b = next_u8()?;
+            Some(Color::Rgb(RgbColor(r, g, b)))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a cumulative style — the result of applying a series of SGR
+/// sequences — to the equivalent style with no disabled attributes or default
+/// colors
+pub(crate) fn effective(style: Style) -> Style {
+    let unset_default = |c: Option<Color>| c.filter(|&c| c != Color::Default);
+    Style::new()
+        .foreground(unset_default(style.get_foreground()))
+        .background(unset_default(style.get_background()))
+        .enabled_attributes(style.get_enabled_attributes())
+}
+
+/// Pairs of attributes that are turned off by a single shared SGR parameter
+const SHARED_OFF: [(Attribute, Attribute, u8); 4] = [
+    (Attribute::Bold, Attribute::Dim, 22),
+    (Attribute::Underline, Attribute::Underline2, 24),
+    (Attribute::Blink, Attribute::Blink2, 25),
+    (Attribute::Frame, Attribute::Encircle, 54),
+];
+
+/// Return the SGR escape sequence that changes text displayed with the
+/// effective style `from` to be displayed with the effective style `to`, or
+/// `None` if the styles are the same.
+///
+/// Both styles are interpreted as described by [`Style::transition()`].
+pub(crate) fn transition_sgr(from: Style, to: Style) -> Option<String> {
+    let change = Style::transition(from, to);
+    if change.is_empty() {
+        return None;
+    }
+    if effective(to).is_empty() {
+        return Some(String::from("\x1B[0m"));
+    }
+    let mut params = Vec::new();
+    let mut enabled = change.get_enabled_attributes();
+    let mut disabled = change.get_disabled_attributes();
+    for (a, b, off) in SHARED_OFF {
+        if disabled.contains(a) || disabled.contains(b) {
+            params.push(off.to_string());
+            disabled -= a | b;
+            // Re-enable whichever member of the pair is still wanted:
+            enabled |= (a | b) & to.get_enabled_attributes();
+        }
+    }
+    for attr in disabled {
+        let off = match attr {
+            Attribute::Italic => 23,
+            Attribute::Reverse => 27,
+            Attribute::Conceal => 28,
+            Attribute::Strike => 29,
+            Attribute::Overline => 55,
+            // Handled by `SHARED_OFF`:
+            Attribute::Bold
+            | Attribute::Dim
+            | Attribute::Underline
+            | Attribute::Underline2
+            | Attribute::Blink
+            | Attribute::Blink2
+            | Attribute::Frame
+            | Attribute::Encircle => continue,
+        };
+        params.push(off.to_string());
+    }
+    for attr in enabled {
+        let on = match attr {
+            Attribute::Bold => 1,
+            Attribute::Dim => 2,
+            Attribute::Italic => 3,
+            Attribute::Underline => 4,
+            Attribute::Blink => 5,
+            Attribute::Blink2 => 6,
+            Attribute::Reverse => 7,
+            Attribute::Conceal => 8,
+            Attribute::Strike => 9,
+            Attribute::Underline2 => 21,
+            Attribute::Frame => 51,
+            Attribute::Encircle => 52,
+            Attribute::Overline => 53,
+        };
+        params.push(on.to_string());
+    }
+    if let Some(fg) = change.get_foreground() {
+        params.push(color_param(fg, 30, 90));
+    }
+    if let Some(bg) = change.get_background() {
+        params.push(color_param(bg, 40, 100));
+    }
+    Some(format!("\x1B[{}m", params.join(";")))
+}
+
+/// Return the semicolon-separated SGR parameters for displaying text in the
+/// effective style `style` after a reset.  An empty style is represented by
+/// `"0"`.
+pub(crate) fn style_sgr_params(style: Style) -> String {
+    transition_sgr(Style::new(), effective(style))
+        .and_then(|seq| Some(seq.strip_prefix("\x1B[")?.strip_suffix('m')?.to_owned()))
+        .unwrap_or_else(|| String::from("0"))
+}
+
+/// Return the SGR parameter(s) for setting the foreground or background color
+/// to `color`.  `base` is 30 for foreground colors and 40 for background
+/// colors, and `bright_base` is 90 and 100, respectively.
+fn color_param(color: Color, base: u8, bright_base: u8) -> String {
+    match color {
+        Color::Default => (base + 9).to_string(),
+        Color::Color256(Color256(n @ 0..=7)) => (base + n).to_string(),
+        Color::Color256(Color256(n @ 8..=15)) => (bright_base + n - 8).to_string(),
+        Color::Color256(Color256(n)) => format!("{};5;{n}", base + 8),
+        Color::Rgb(RgbColor(r, g, b)) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", Style::new())]
+    #[case("0", Style::new())]
+    #[case("1", Style::new().bold())]
+    #[case("01;34", Color256::BLUE.as_foreground().bold())]
+    #[case("1;31;44", Color256::RED.on(Color256::BLUE).bold())]
+    #[case("93;101", Color256::BRIGHT_YELLOW.on(Color256::BRIGHT_RED))]
+    #[case("39;49", Color::Default.on(Color::Default))]
+    #[case("38;5;208", Color256(208).as_foreground())]
+    #[case("48;2;1;2;3", RgbColor(1, 2, 3).as_background())]
+    #[case("1;0;3", Style::new().italic())]
+    #[case("22;24", Style::new().not_bold().not_dim().not_underline().not_underline2())]
+    #[case("2;3;4;5;6;7;8;9", Style::new().dim().italic().underline().blink().blink2().reverse().conceal().strike())]
+    #[case("21;51;52;53", Style::new().underline2().frame().encircle().overline())]
+    #[case("1;foo;3", Style::new().bold().italic())]
+    #[case("1;999;3", Style::new().bold().italic())]
+    #[case("38;5", Style::new())]
+    #[case("38;7;1", Style::new().bold())]
+    fn parse(#[case] params: &str, #[case] style: Style) {
+        assert_eq!(
+            apply_sgr_params(Style::new(), split_sgr_params(params)),
+            style
+        );
+    }
+
+    #[rstest]
+    #[case(Style::new(), Style::new(), None)]
+    #[case(Style::new().bold(), Style::new().bold(), None)]
+    #[case(Style::new().bold(), Style::new(), Some("0"))]
+    #[case(Style::new().bold(), Style::new().not_bold(), Some("0"))]
+    #[case(Style::new(), Color::Default.on(Color::Default), None)]
+    #[case(Style::new().bold(), Style::new().bold().italic(), Some("3"))]
+    #[case(Style::new().bold().italic(), Style::new().italic(), Some("22"))]
+    #[case(Style::new().bold().dim(), Style::new().dim(), Some("22;2"))]
+    #[case(Style::new().blink2().reverse(), Style::new().blink().conceal(), Some("25;27;5;8"))]
+    #[case(Style::new().bold(), Color256::RED.as_foreground().bold(), Some("31"))]
+    #[case(Color256::RED.on(Color256::BLUE), Color256::BRIGHT_RED.as_background(), Some("39;101"))]
+    #[case(Style::new(), Color256(208).on(RgbColor(1, 2, 3)), Some("38;5;208;48;2;1;2;3"))]
+    fn transition(#[case] from: Style, #[case] to: Style, #[case] params: Option<&str>) {
+        assert_eq!(
+            transition_sgr(from, to),
+            params.map(|p| format!("\x1B[{p}m"))
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use super::attributes::{Attribute, AttributeSet};
 use super::color::Color;
+use crate::contrast::Readability;
+use crate::palette::Palette;
+use crate::rgbcolor::RgbColor;
 use std::fmt;
 use thiserror::Error;
 
@@ -116,8 +119,8 @@ impl Style {
 
     /// Combine two styles, applying the effects of `other` after `self`
     pub fn patch(self, other: Style) -> Style {
-        let foreground = self.foreground.or(other.foreground);
-        let background = self.background.or(other.background);
+        let foreground = other.foreground.or(self.foreground);
+        let background = other.background.or(self.background);
         let enabled_attributes =
             (self.enabled_attributes - other.disabled_attributes) | other.enabled_attributes;
         let disabled_attributes =
@@ -130,6 +133,176 @@ impl Style {
         }
     }
 
+    /// Compute the minimal style that, when [patched][Style::patch] onto text
+    /// currently styled with `from`, results in text styled with `to`.
+    ///
+    /// Both `from` and `to` are treated as complete descriptions of the
+    /// on-screen style: a color of `None` is taken to mean the terminal's
+    /// default color, and an attribute that is not enabled is taken to be
+    /// off.  The returned style enables the attributes enabled in `to` but
+    /// not in `from`, disables the attributes enabled in `from` but not in
+    /// `to`, and sets a foreground or background color only when it differs
+    /// between the two styles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::Style;
+    ///
+    /// let from = "bold red on blue".parse::<Style>().unwrap();
+    /// let to = "italic red".parse::<Style>().unwrap();
+    /// assert_eq!(
+    ///     Style::transition(from, to).to_string(),
+    ///     "not bold italic on default"
+    /// );
+    /// ```
+    pub fn transition(from: Style, to: Style) -> Style {
+        fn color_change(from: Option<Color>, to: Option<Color>) -> Option<Color> {
+            let from = from.unwrap_or_default();
+            let to = to.unwrap_or_default();
+            (from != to).then_some(to)
+        }
+
+        let from_attrs = from.enabled_attributes - from.disabled_attributes;
+        let to_attrs = to.enabled_attributes - to.disabled_attributes;
+        Style {
+            foreground: color_change(from.foreground, to.foreground),
+            background: color_change(from.background, to.background),
+            enabled_attributes: to_attrs - from_attrs,
+            disabled_attributes: from_attrs - to_attrs,
+        }
+    }
+
+    /// Return the style that reverts this style back to the terminal's default
+    /// style.
+    ///
+    /// The returned style disables every attribute enabled by `self` and
+    /// resets the foreground and/or background color to [`Color::Default`] if
+    /// `self` sets them.  This is equivalent to `Style::transition(self,
+    /// Style::new())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::Style;
+    ///
+    /// let style = "bold red".parse::<Style>().unwrap();
+    /// assert_eq!(style.undo().to_string(), "not bold default");
+    /// ```
+    pub fn undo(self) -> Style {
+        Style::transition(self, Style::new())
+    }
+
+    /// Remove any disabled attributes from `self` that are not enabled in
+    /// `base`.
+    ///
+    /// When `self` is applied on top of text styled with `base`, disabling an
+    /// attribute that `base` does not enable has no effect, and so such
+    /// attributes can be dropped from the style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::Style;
+    ///
+    /// let base = "bold blue".parse::<Style>().unwrap();
+    /// let style = "not bold not italic red".parse::<Style>().unwrap();
+    /// assert_eq!(style.normalize(base).to_string(), "not bold red");
+    /// ```
+    pub fn normalize(mut self, base: Style) -> Style {
+        self.disabled_attributes &= base.enabled_attributes - base.disabled_attributes;
+        self
+    }
+
+    /// Determine the foreground & background colors that text styled with
+    /// `self` will actually be displayed with.
+    ///
+    /// `base_fg` and `base_bg` are the terminal's default foreground and
+    /// background colors; they are used in place of unset colors and
+    /// [`Color::Default`].  If the style enables [`Attribute::Reverse`], the
+    /// resulting foreground and background colors are swapped.  If the style
+    /// enables [`Attribute::Conceal`], the resulting foreground color is set
+    /// to the resulting background color.
+    ///
+    /// The return value is a `(foreground, background)` pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Color, Color256, RgbColor, Style};
+    ///
+    /// let fg = Color::from(RgbColor(0xDD, 0xDD, 0xDD));
+    /// let bg = Color::from(RgbColor(0x11, 0x11, 0x11));
+    /// let style = "reverse red".parse::<Style>().unwrap();
+    /// assert_eq!(style.resolve(fg, bg), (bg, Color256::RED.into()));
+    /// ```
+    pub fn resolve(self, base_fg: Color, base_bg: Color) -> (Color, Color) {
+        fn or_base(c: Option<Color>, base: Color) -> Color {
+            match c {
+                None | Some(Color::Default) => base,
+                Some(c) => c,
+            }
+        }
+
+        let mut fg = or_base(self.foreground, base_fg);
+        let mut bg = or_base(self.background, base_bg);
+        if self.is_enabled(Attribute::Reverse) {
+            std::mem::swap(&mut fg, &mut bg);
+        }
+        if self.is_enabled(Attribute::Conceal) {
+            fg = bg;
+        }
+        (fg, bg)
+    }
+
+    /// Return the [WCAG 2.x contrast ratio][wcag] between the foreground and
+    /// background colors that text styled with `self` is displayed with.
+    ///
+    /// The displayed colors are determined by [`Style::resolve()`], with
+    /// [`Color256`][crate::Color256] values converted to RGB using `palette`.
+    /// `default_fg` and `default_bg` are the terminal's default foreground and
+    /// background colors.
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(
+        self,
+        palette: &Palette,
+        default_fg: RgbColor,
+        default_bg: RgbColor,
+    ) -> f64 {
+        let (fg, bg) = self.resolve(default_fg.into(), default_bg.into());
+        let fg = palette.resolve(fg, default_fg);
+        let bg = palette.resolve(bg, default_bg);
+        fg.contrast_ratio(bg)
+    }
+
+    /// Return the [`Readability`] level achieved by the
+    /// [contrast ratio][Style::contrast_ratio] of text styled with `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Palette, Readability, RgbColor, Style};
+    ///
+    /// let fg = RgbColor(0xC0, 0xC0, 0xC0);
+    /// let bg = RgbColor(0x00, 0x00, 0x00);
+    /// let style = "blue on black".parse::<Style>().unwrap();
+    /// assert_eq!(
+    ///     style.readability(&Palette::XTERM, fg, bg),
+    ///     Readability::Insufficient
+    /// );
+    /// let style = "bright_yellow".parse::<Style>().unwrap();
+    /// assert_eq!(style.readability(&Palette::XTERM, fg, bg), Readability::Aaa);
+    /// ```
+    pub fn readability(
+        self,
+        palette: &Palette,
+        default_fg: RgbColor,
+        default_bg: RgbColor,
+    ) -> Readability {
+        Readability::from_contrast_ratio(self.contrast_ratio(palette, default_fg, default_bg))
+    }
+
     /// Enable the given attribute(s)
     pub fn enable<A: Into<AttributeSet>>(mut self, attrs: A) -> Style {
         let attrs = attrs.into();
@@ -298,6 +471,59 @@ impl From<AttributeSet> for Style {
     }
 }
 
+impl<S: Into<Style>> std::ops::Add<S> for Style {
+    type Output = Style;
+
+    /// Combine two styles with [`Style::patch()`]
+    fn add(self, rhs: S) -> Style {
+        self.patch(rhs.into())
+    }
+}
+
+impl<S: Into<Style>> std::ops::AddAssign<S> for Style {
+    fn add_assign(&mut self, rhs: S) {
+        *self = self.patch(rhs.into());
+    }
+}
+
+impl<A: Into<AttributeSet>> std::ops::BitOr<A> for Style {
+    type Output = Style;
+
+    /// Enable the given attribute(s)
+    fn bitor(self, rhs: A) -> Style {
+        self.enable(rhs)
+    }
+}
+
+impl<A: Into<AttributeSet>> std::ops::BitOrAssign<A> for Style {
+    fn bitor_assign(&mut self, rhs: A) {
+        *self = self.enable(rhs);
+    }
+}
+
+impl<A: Into<AttributeSet>> std::ops::Sub<A> for Style {
+    type Output = Style;
+
+    /// Disable the given attribute(s)
+    fn sub(self, rhs: A) -> Style {
+        self.disable(rhs)
+    }
+}
+
+impl<A: Into<AttributeSet>> std::ops::SubAssign<A> for Style {
+    fn sub_assign(&mut self, rhs: A) {
+        *self = self.disable(rhs);
+    }
+}
+
+impl std::iter::Sum for Style {
+    /// Combine a sequence of styles by [patching][Style::patch] each one onto
+    /// the combination of the styles before it
+    fn sum<I: Iterator<Item = Style>>(iter: I) -> Style {
+        iter.fold(Style::new(), Style::patch)
+    }
+}
+
 #[cfg(feature = "anstyle")]
 #[cfg_attr(docsrs, doc(cfg(feature = "anstyle")))]
 impl From<Style> for anstyle::Style {
@@ -354,6 +580,117 @@ impl From<anstyle::Style> for Style {
     }
 }
 
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<Style> for colored::ColoredString {
+    /// Convert a `Style` to a [`colored::ColoredString`] with empty input
+    /// text.  Set the `input` field of the result to apply the style to some
+    /// text.
+    ///
+    /// # Data Loss
+    ///
+    /// If the `Style`'s foreground or background color is [`Color::Default`],
+    /// it will be converted to `None`.
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use colored::ColoredString;
+    /// use parse_style::Style;
+    ///
+    /// let style = "bold red".parse::<Style>().unwrap();
+    /// let mut s = ColoredString::from(style);
+    /// s.input = String::from("Hello");
+    /// assert_eq!(s.fgcolor, Some(colored::Color::Red));
+    /// ```
+    fn from(value: Style) -> colored::ColoredString {
+        let mut s = colored::ColoredString::default();
+        s.fgcolor = value
+            .foreground
+            .and_then(|c| colored::Color::try_from(c).ok());
+        s.bgcolor = value
+            .background
+            .and_then(|c| colored::Color::try_from(c).ok());
+        s.style = value.enabled_attributes.into();
+        s
+    }
+}
+
+#[cfg(feature = "colored")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colored")))]
+impl From<&colored::ColoredString> for Style {
+    /// Convert the colors & styles of a [`colored::ColoredString`] to a
+    /// `Style`
+    fn from(value: &colored::ColoredString) -> Style {
+        Style::new()
+            .foreground(value.fgcolor.map(Color::from))
+            .background(value.bgcolor.map(Color::from))
+            .enabled_attributes(value.style)
+    }
+}
+
+#[cfg(feature = "console")]
+#[cfg_attr(docsrs, doc(cfg(feature = "console")))]
+impl From<Style> for console::Style {
+    /// Convert a `Style` to a [`console::Style`]
+    ///
+    /// Foreground & background colors 8 through 15 are converted to the
+    /// corresponding named colors with the "bright" flag set.
+    ///
+    /// # Data Loss
+    ///
+    /// If the `Style`'s foreground or background color is [`Color::Default`],
+    /// it will be discarded.
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn from(value: Style) -> console::Style {
+        // Returns the color to use and whether it should be bright
+        let convert = |c: Color| match c {
+            Color::Default => None,
+            Color::Color256(c) if (8..16).contains(&c.0) => {
+                Some((console::Color::from(crate::Color256(c.0 - 8)), true))
+            }
+            c => console::Color::try_from(c).ok().map(|c| (c, false)),
+        };
+        let mut style = console::Style::new();
+        if let Some((fg, bright)) = value.foreground.and_then(convert) {
+            style = style.fg(fg);
+            if bright {
+                style = style.bright();
+            }
+        }
+        if let Some((bg, bright)) = value.background.and_then(convert) {
+            style = style.bg(bg);
+            if bright {
+                style = style.on_bright();
+            }
+        }
+        for attr in value.enabled_attributes {
+            if let Ok(a) = console::Attribute::try_from(attr) {
+                style = style.attr(a);
+            }
+        }
+        style
+    }
+}
+
 #[cfg(feature = "crossterm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 impl From<crossterm::style::Attributes> for Style {
@@ -486,6 +823,193 @@ impl From<crossterm::style::ContentStyle> for Style {
     }
 }
 
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<Style> for cursive_core::style::Style {
+    /// Convert a `Style` to a [`cursive_core::style::Style`]
+    ///
+    /// Colors that are not set by the `Style` are inherited from the parent
+    /// style, as are the effects for attributes that the `Style` neither
+    /// enables nor disables.
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion, as they have
+    /// no `cursive_core::style::Effect` equivalents:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Conceal`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    fn from(value: Style) -> cursive_core::style::Style {
+        let mut effects = cursive_core::style::Effects::from(value.enabled_attributes);
+        for effect in value
+            .disabled_attributes
+            .into_iter()
+            .filter_map(|attr| cursive_core::style::Effect::try_from(attr).ok())
+        {
+            effects.statuses[effect] = cursive_core::style::EffectStatus::Off;
+        }
+        cursive_core::style::Style {
+            effects,
+            color: value.into(),
+        }
+    }
+}
+
+#[cfg(feature = "cursive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursive")))]
+impl From<Style> for cursive_core::style::ColorStyle {
+    /// Convert a `Style` to a [`cursive_core::style::ColorStyle`]
+    ///
+    /// Colors that are not set by the `Style` are inherited from the parent
+    /// style.
+    ///
+    /// # Data Loss
+    ///
+    /// Attributes are discarded during conversion.
+    fn from(value: Style) -> cursive_core::style::ColorStyle {
+        let convert = |c: Option<Color>| {
+            c.map_or(cursive_core::style::ColorType::InheritParent, |c| {
+                cursive_core::style::ColorType::Color(c.into())
+            })
+        };
+        cursive_core::style::ColorStyle {
+            front: convert(value.foreground),
+            back: convert(value.background),
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+impl From<Style> for epaint::text::TextFormat {
+    /// Convert a `Style` to an [`epaint::text::TextFormat`], resolving
+    /// [`Color256`][crate::Color256] values using [`Palette::XTERM`] and
+    /// taking all unset properties from [`TextFormat::default()`]
+    ///
+    /// To use a different palette or base format, call
+    /// [`egui::text_format()`][crate::egui::text_format] instead.
+    ///
+    /// [`TextFormat::default()`]: epaint::text::TextFormat::default
+    ///
+    /// # Data Loss
+    ///
+    /// See [`egui::text_format()`][crate::egui::text_format].
+    fn from(value: Style) -> epaint::text::TextFormat {
+        crate::egui::text_format(value, &Palette::XTERM, epaint::text::TextFormat::default())
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<Style> for nu_ansi_term::Style {
+    /// Convert a `Style` to a [`nu_ansi_term::Style`]
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn from(value: Style) -> nu_ansi_term::Style {
+        nu_ansi_term::Style {
+            foreground: value.foreground.map(nu_ansi_term::Color::from),
+            background: value.background.map(nu_ansi_term::Color::from),
+            ..nu_ansi_term::Style::from(value.enabled_attributes)
+        }
+    }
+}
+
+#[cfg(feature = "nu-ansi-term")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nu-ansi-term")))]
+impl From<nu_ansi_term::Style> for Style {
+    /// Convert a [`nu_ansi_term::Style`] to a `Style`
+    ///
+    /// # Data Loss
+    ///
+    /// The `prefix_with_reset` flag is discarded during conversion.
+    fn from(value: nu_ansi_term::Style) -> Style {
+        let mut style = Style::new()
+            .foreground(value.foreground.map(Color::from))
+            .background(value.background.map(Color::from));
+        for (enabled, attr) in [
+            (value.is_bold, Attribute::Bold),
+            (value.is_dimmed, Attribute::Dim),
+            (value.is_italic, Attribute::Italic),
+            (value.is_underline, Attribute::Underline),
+            (value.is_blink, Attribute::Blink),
+            (value.is_reverse, Attribute::Reverse),
+            (value.is_hidden, Attribute::Conceal),
+            (value.is_strikethrough, Attribute::Strike),
+        ] {
+            if enabled {
+                style = style.enable(attr);
+            }
+        }
+        style
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<Style> for owo_colors::Style {
+    /// Convert a `Style` to an [`owo_colors::Style`]
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn from(value: Style) -> owo_colors::Style {
+        let mut style = owo_colors::Style::new();
+        if let Some(fg) = value.foreground {
+            style = style.color(owo_colors::DynColors::from(fg));
+        }
+        if let Some(bg) = value.background {
+            style = style.on_color(owo_colors::DynColors::from(bg));
+        }
+        for attr in value.enabled_attributes {
+            if let Ok(effect) = owo_colors::Effect::try_from(attr) {
+                style = style.effect(effect);
+            }
+        }
+        style
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "owo-colors")))]
+impl From<owo_colors::Style> for Style {
+    /// Convert an [`owo_colors::Style`] to a `Style`
+    ///
+    /// [`owo_colors::CssColors`] values are converted to RGB colors.
+    fn from(value: owo_colors::Style) -> Style {
+        // `owo_colors::Style` does not expose its fields, so we instead parse
+        // the escape sequence that it emits.
+        let prefix = value.prefix_formatter().to_string();
+        match prefix
+            .strip_prefix("\x1b[")
+            .and_then(|s| s.strip_suffix('m'))
+        {
+            Some(params) => crate::sgr::parse_sgr_params(params),
+            None => Style::new(),
+        }
+    }
+}
+
 #[cfg(feature = "ratatui")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
 impl From<Style> for ratatui_core::style::Style {
@@ -537,72 +1061,468 @@ impl From<ratatui_core::style::Style> for Style {
     }
 }
 
-impl fmt::Display for Style {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut first = true;
-        for attr in Attribute::iter() {
-            if self.is_enabled(attr) {
-                if !std::mem::replace(&mut first, false) {
-                    write!(f, " ")?;
-                }
-                if f.alternate() {
-                    write!(f, "{attr:#}")?;
-                } else {
-                    write!(f, "{attr}")?;
-                }
-            } else if self.is_disabled(attr) {
-                if !std::mem::replace(&mut first, false) {
-                    write!(f, " ")?;
-                }
-                if f.alternate() {
-                    write!(f, "not {attr:#}")?;
-                } else {
-                    write!(f, "not {attr}")?;
-                }
-            }
-        }
-        if let Some(fg) = self.foreground {
-            if !std::mem::replace(&mut first, false) {
-                write!(f, " ")?;
-            }
-            write!(f, "{fg}")?;
-        }
-        if let Some(bg) = self.background {
-            if !std::mem::replace(&mut first, false) {
-                write!(f, " ")?;
-            }
-            write!(f, "on {bg}")?;
-        }
-        if first {
-            write!(f, "none")?;
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<Style> for syntect::highlighting::StyleModifier {
+    /// Convert a `Style` to a [`syntect::highlighting::StyleModifier`],
+    /// resolving [`Color256`][crate::Color256] values using
+    /// [`Palette::XTERM`]
+    ///
+    /// If the `Style` enables or disables any attributes, the resulting
+    /// modifier's font style is set to the enabled attributes; otherwise, the
+    /// modifier leaves the font style unchanged.
+    ///
+    /// # Data Loss
+    ///
+    /// If the `Style`'s foreground or background color is [`Color::Default`],
+    /// it will be converted to `None`.
+    ///
+    /// All attributes other than [`Attribute::Bold`], [`Attribute::Italic`],
+    /// and [`Attribute::Underline`] are discarded during conversion.
+    fn from(value: Style) -> syntect::highlighting::StyleModifier {
+        let font_style =
+            if value.enabled_attributes.is_empty() && value.disabled_attributes.is_empty() {
+                None
+            } else {
+                Some(value.enabled_attributes.into())
+            };
+        syntect::highlighting::StyleModifier {
+            foreground: value
+                .foreground
+                .and_then(|c| syntect::highlighting::Color::try_from(c).ok()),
+            background: value
+                .background
+                .and_then(|c| syntect::highlighting::Color::try_from(c).ok()),
+            font_style,
         }
-        Ok(())
     }
 }
 
-impl std::str::FromStr for Style {
-    type Err = ParseStyleError;
-
-    fn from_str(s: &str) -> Result<Style, ParseStyleError> {
-        let mut style = Style::new();
-        if s.is_empty() || s.trim().eq_ignore_ascii_case("none") {
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<syntect::highlighting::StyleModifier> for Style {
+    /// Convert a [`syntect::highlighting::StyleModifier`] to a `Style`
+    ///
+    /// If the modifier sets the font style, the resulting `Style` enables the
+    /// attributes in the font style and disables the remaining attributes
+    /// that a font style can express ([`Attribute::Bold`],
+    /// [`Attribute::Italic`], and [`Attribute::Underline`]).
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha components of the colors are discarded during conversion.
+    fn from(value: syntect::highlighting::StyleModifier) -> Style {
+        let mut style = Style::new()
+            .foreground(value.foreground.map(Color::from))
+            .background(value.background.map(Color::from));
+        if let Some(fs) = value.font_style {
+            let enabled = AttributeSet::from(fs);
+            let expressible = Attribute::Bold | Attribute::Italic | Attribute::Underline;
+            style = style
+                .enabled_attributes(enabled)
+                .disabled_attributes(expressible - enabled);
+        }
+        style
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl TryFrom<Style> for syntect::highlighting::Style {
+    type Error = crate::ConversionError;
+
+    /// Convert a `Style` to a [`syntect::highlighting::Style`], resolving
+    /// [`Color256`][crate::Color256] values using [`Palette::XTERM`]
+    ///
+    /// To convert a `Style` that lacks a foreground or background color,
+    /// convert it to a [`syntect::highlighting::StyleModifier`] instead and
+    /// apply that to a base style.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the `Style`'s foreground or background color is
+    /// `None` or [`Color::Default`]
+    ///
+    /// # Data Loss
+    ///
+    /// All attributes other than [`Attribute::Bold`], [`Attribute::Italic`],
+    /// and [`Attribute::Underline`] are discarded during conversion.
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn try_from(value: Style) -> Result<syntect::highlighting::Style, crate::ConversionError> {
+        let convert = |c: Option<Color>| {
+            c.ok_or(crate::ConversionError)
+                .and_then(syntect::highlighting::Color::try_from)
+        };
+        Ok(syntect::highlighting::Style {
+            foreground: convert(value.foreground)?,
+            background: convert(value.background)?,
+            font_style: value.enabled_attributes.into(),
+        })
+    }
+}
+
+#[cfg(feature = "syntect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "syntect")))]
+impl From<syntect::highlighting::Style> for Style {
+    /// Convert a [`syntect::highlighting::Style`] to a `Style`
+    ///
+    /// # Data Loss
+    ///
+    /// The alpha components of the colors are discarded during conversion.
+    fn from(value: syntect::highlighting::Style) -> Style {
+        Style::new()
+            .foreground(Some(value.foreground.into()))
+            .background(Some(value.background.into()))
+            .enabled_attributes(value.font_style)
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<Style> for termcolor::ColorSpec {
+    /// Convert a `Style` to a [`termcolor::ColorSpec`]
+    ///
+    /// `termcolor` only supports bright versions of the eight named colors via
+    /// a single "intense" flag that applies to both the foreground and the
+    /// background.  Thus, if either color is one of [`Color256`][crate::Color256] colors 8
+    /// through 15, the resulting `ColorSpec` will be intense, and the bright
+    /// colors will be converted to the corresponding named colors.  When this
+    /// happens, any other color in the range 0 through 7 is converted to a
+    /// [`termcolor::Color::Ansi256`] value so that it is not brightened as
+    /// well.  Otherwise, colors 0 through 7 are converted to named colors, and
+    /// all other colors are converted to `Ansi256` or `Rgb` values.
+    ///
+    /// # Data Loss
+    ///
+    /// If the `Style`'s foreground or background color is [`Color::Default`],
+    /// it will be converted to `None`.
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Blink`]
+    /// - [`Attribute::Blink2`]
+    /// - [`Attribute::Reverse`]
+    /// - [`Attribute::Conceal`]
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn from(value: Style) -> termcolor::ColorSpec {
+        let is_bright = |c: Option<Color>| matches!(c, Some(Color::Color256(crate::Color256(i))) if (8..16).contains(&i));
+        let intense = is_bright(value.foreground) || is_bright(value.background);
+        let convert = |c: Option<Color>| match c? {
+            Color::Default => None,
+            Color::Color256(crate::Color256(i @ 0..8)) if !intense => Some(termcolor_named(i)),
+            Color::Color256(crate::Color256(i @ 8..16)) => Some(termcolor_named(i - 8)),
+            Color::Color256(c) => Some(c.into()),
+            Color::Rgb(c) => Some(c.into()),
+        };
+        let mut spec = termcolor::ColorSpec::new();
+        spec.set_fg(convert(value.foreground))
+            .set_bg(convert(value.background))
+            .set_intense(intense)
+            .set_bold(value.is_enabled(Attribute::Bold))
+            .set_dimmed(value.is_enabled(Attribute::Dim))
+            .set_italic(value.is_enabled(Attribute::Italic))
+            .set_underline(value.is_enabled(Attribute::Underline))
+            .set_strikethrough(value.is_enabled(Attribute::Strike));
+        spec
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<termcolor::ColorSpec> for Style {
+    /// Convert a [`termcolor::ColorSpec`] to a `Style`
+    ///
+    /// If the `ColorSpec` is intense, named foreground & background colors
+    /// are converted to [`Color256`][crate::Color256] colors 8 through 15; otherwise, they are
+    /// converted to colors 0 through 7.
+    ///
+    /// # Data Loss
+    ///
+    /// The "reset" flag is discarded during conversion.
+    ///
+    /// If the `ColorSpec` is intense but neither its foreground nor its
+    /// background is a named color, the intensity is discarded.
+    fn from(value: termcolor::ColorSpec) -> Style {
+        Style::from(&value)
+    }
+}
+
+#[cfg(feature = "termcolor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "termcolor")))]
+impl From<&termcolor::ColorSpec> for Style {
+    /// Convert a [`termcolor::ColorSpec`] to a `Style`
+    ///
+    /// See the conversion from an owned `ColorSpec` for details.
+    fn from(value: &termcolor::ColorSpec) -> Style {
+        let convert = |c: Option<&termcolor::Color>| {
+            let c = *c?;
+            let named = !matches!(c, termcolor::Color::Ansi256(_) | termcolor::Color::Rgb(..));
+            match Color::from(c) {
+                Color::Color256(crate::Color256(i)) if named && value.intense() => {
+                    Some(crate::Color256(i + 8).into())
+                }
+                color => Some(color),
+            }
+        };
+        let mut style = Style::new()
+            .foreground(convert(value.fg()))
+            .background(convert(value.bg()));
+        for (enabled, attr) in [
+            (value.bold(), Attribute::Bold),
+            (value.dimmed(), Attribute::Dim),
+            (value.italic(), Attribute::Italic),
+            (value.underline(), Attribute::Underline),
+            (value.strikethrough(), Attribute::Strike),
+        ] {
+            if enabled {
+                style = style.enable(attr);
+            }
+        }
+        style
+    }
+}
+
+/// Return the named [`termcolor::Color`] for the given index in the range 0
+/// through 7
+#[cfg(feature = "termcolor")]
+fn termcolor_named(index: u8) -> termcolor::Color {
+    match index {
+        0 => termcolor::Color::Black,
+        1 => termcolor::Color::Red,
+        2 => termcolor::Color::Green,
+        3 => termcolor::Color::Yellow,
+        4 => termcolor::Color::Blue,
+        5 => termcolor::Color::Magenta,
+        6 => termcolor::Color::Cyan,
+        _ => termcolor::Color::White,
+    }
+}
+
+/// A wrapper around a [`Style`] that displays as the escape sequences for
+/// applying the style, written using [`termion::color::Fg`],
+/// [`termion::color::Bg`], and the writers in [`termion::style`]
+///
+/// # Data Loss
+///
+/// The following attributes have no `termion::style` equivalents and are not
+/// written:
+///
+/// - [`Attribute::Blink2`]
+/// - [`Attribute::Conceal`]
+/// - [`Attribute::Underline2`]
+/// - [`Attribute::Encircle`]
+/// - [`Attribute::Overline`]
+///
+/// In addition, disabling [`Attribute::Frame`] has no `termion::style`
+/// equivalent, and disabling either of [`Attribute::Bold`] or
+/// [`Attribute::Dim`] is written as [`termion::style::NoFaint`], which
+/// disables both.
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style, TermionStyle};
+/// use termion::color::{Bg, Fg};
+///
+/// let style = Color256::RED.on(Color256::BLUE).bold();
+/// assert_eq!(
+///     TermionStyle::from(style).to_string(),
+///     format!("{}{}{}", Fg(Color256::RED), Bg(Color256::BLUE), termion::style::Bold),
+/// );
+/// ```
+#[cfg(all(unix, feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "termion"))))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TermionStyle(Style);
+
+#[cfg(all(unix, feature = "termion"))]
+impl TermionStyle {
+    /// Return the wrapped `Style`
+    pub const fn style(self) -> Style {
+        self.0
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+impl From<Style> for TermionStyle {
+    fn from(value: Style) -> TermionStyle {
+        TermionStyle(value)
+    }
+}
+
+#[cfg(all(unix, feature = "termion"))]
+impl fmt::Display for TermionStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use termion::color::{Bg, Fg};
+        use termion::style;
+        if let Some(fg) = self.0.foreground {
+            write!(f, "{}", Fg(fg))?;
+        }
+        if let Some(bg) = self.0.background {
+            write!(f, "{}", Bg(bg))?;
+        }
+        for attr in self.0.enabled_attributes {
+            match attr {
+                Attribute::Bold => write!(f, "{}", style::Bold)?,
+                Attribute::Dim => write!(f, "{}", style::Faint)?,
+                Attribute::Italic => write!(f, "{}", style::Italic)?,
+                Attribute::Underline => write!(f, "{}", style::Underline)?,
+                Attribute::Blink => write!(f, "{}", style::Blink)?,
+                Attribute::Blink2 => (),
+                Attribute::Reverse => write!(f, "{}", style::Invert)?,
+                Attribute::Conceal => (),
+                Attribute::Strike => write!(f, "{}", style::CrossedOut)?,
+                Attribute::Underline2 => (),
+                Attribute::Frame => write!(f, "{}", style::Framed)?,
+                Attribute::Encircle => (),
+                Attribute::Overline => (),
+            }
+        }
+        let disabled = self.0.disabled_attributes;
+        if disabled.contains(Attribute::Bold) || disabled.contains(Attribute::Dim) {
+            write!(f, "{}", style::NoFaint)?;
+        }
+        for attr in disabled {
+            match attr {
+                Attribute::Bold | Attribute::Dim => (),
+                Attribute::Italic => write!(f, "{}", style::NoItalic)?,
+                Attribute::Underline => write!(f, "{}", style::NoUnderline)?,
+                Attribute::Blink => write!(f, "{}", style::NoBlink)?,
+                Attribute::Blink2 => (),
+                Attribute::Reverse => write!(f, "{}", style::NoInvert)?,
+                Attribute::Conceal => (),
+                Attribute::Strike => write!(f, "{}", style::NoCrossedOut)?,
+                Attribute::Underline2 => (),
+                Attribute::Frame => (),
+                Attribute::Encircle => (),
+                Attribute::Overline => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<Style> for yansi::Style {
+    /// Convert a `Style` to a [`yansi::Style`]
+    ///
+    /// # Data Loss
+    ///
+    /// The following attributes are discarded during conversion:
+    ///
+    /// - [`Attribute::Underline2`]
+    /// - [`Attribute::Frame`]
+    /// - [`Attribute::Encircle`]
+    /// - [`Attribute::Overline`]
+    ///
+    /// Disabled attributes are discarded during conversion.
+    fn from(value: Style) -> yansi::Style {
+        let mut style = yansi::Style::from(value.enabled_attributes);
+        style.foreground = value.foreground.map(yansi::Color::from);
+        style.background = value.background.map(yansi::Color::from);
+        style
+    }
+}
+
+#[cfg(feature = "yansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yansi")))]
+impl From<yansi::Style> for Style {
+    /// Convert a [`yansi::Style`] to a `Style`
+    ///
+    /// The [`yansi::Quirk::Bright`] and [`yansi::Quirk::OnBright`] quirks are
+    /// applied to the colors during conversion.
+    ///
+    /// # Data Loss
+    ///
+    /// All other quirks and the style's condition are discarded during
+    /// conversion.
+    fn from(value: yansi::Style) -> Style {
+        // `yansi::Style` does not expose its attributes, so we instead parse
+        // the escape sequence that it emits.
+        let prefix = value.prefix();
+        match prefix
+            .strip_prefix("\x1b[")
+            .and_then(|s| s.strip_suffix('m'))
+        {
+            Some(params) => crate::sgr::parse_sgr_params(params),
+            None => Style::new(),
+        }
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for attr in Attribute::iter() {
+            if self.is_enabled(attr) {
+                if !std::mem::replace(&mut first, false) {
+                    write!(f, " ")?;
+                }
+                if f.alternate() {
+                    write!(f, "{attr:#}")?;
+                } else {
+                    write!(f, "{attr}")?;
+                }
+            } else if self.is_disabled(attr) {
+                if !std::mem::replace(&mut first, false) {
+                    write!(f, " ")?;
+                }
+                if f.alternate() {
+                    write!(f, "not {attr:#}")?;
+                } else {
+                    write!(f, "not {attr}")?;
+                }
+            }
+        }
+        if let Some(fg) = self.foreground {
+            if !std::mem::replace(&mut first, false) {
+                write!(f, " ")?;
+            }
+            write!(f, "{fg}")?;
+        }
+        if let Some(bg) = self.background {
+            if !std::mem::replace(&mut first, false) {
+                write!(f, " ")?;
+            }
+            write!(f, "on {bg}")?;
+        }
+        if first {
+            write!(f, "none")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Style, ParseStyleError> {
+        let mut style = Style::new();
+        if s.is_empty() || s.trim().eq_ignore_ascii_case("none") {
             return Ok(style);
         }
         let mut words = s.split_whitespace();
         while let Some(token) = words.next() {
             if token.eq_ignore_ascii_case("on") {
-                let Some(bg) = words.next().and_then(|s| s.parse::<Color>().ok()) else {
+                let Some(bg) = words.next().and_then(Color::parse_str) else {
                     return Err(ParseStyleError::MissingBackground);
                 };
                 style.background = Some(bg);
             } else if token.eq_ignore_ascii_case("not") {
-                let Some(attr) = words.next().and_then(|s| s.parse::<Attribute>().ok()) else {
+                let Some(attr) = words.next().and_then(Attribute::parse_str) else {
                     return Err(ParseStyleError::MissingAttribute);
                 };
                 style = style.disable(attr);
-            } else if let Ok(color) = token.parse::<Color>() {
+            } else if let Some(color) = Color::parse_str(token) {
                 style.foreground = Some(color);
-            } else if let Ok(attr) = token.parse::<Attribute>() {
+            } else if let Some(attr) = Attribute::parse_str(token) {
                 style = style.enable(attr);
             } else {
                 return Err(ParseStyleError::Token(token.to_owned()));
@@ -675,6 +1595,884 @@ mod test {
         assert_eq!(Style::new(), Style::default());
     }
 
+    mod patch {
+        use super::*;
+        use crate::Color256;
+
+        #[test]
+        fn other_colors_win() {
+            let base = Color256::RED.on(Color256::BLUE);
+            let style = base.patch(Style::from(Color256::GREEN));
+            assert_eq!(style, Color256::GREEN.on(Color256::BLUE));
+        }
+
+        #[test]
+        fn attributes() {
+            let base = Style::new().bold().not_italic();
+            let style = base.patch(Style::new().not_bold().italic().underline());
+            assert_eq!(style, Style::new().not_bold().italic().underline());
+        }
+    }
+
+    mod transition {
+        use super::*;
+        use crate::Color256;
+        use rstest::rstest;
+
+        #[test]
+        fn identical() {
+            let style = Color256::RED.on(Color256::BLUE).bold();
+            assert_eq!(Style::transition(style, style), Style::new());
+        }
+
+        #[test]
+        fn from_empty() {
+            let style = Color256::RED.on(Color256::BLUE).bold();
+            assert_eq!(Style::transition(Style::new(), style), style);
+        }
+
+        #[test]
+        fn to_empty() {
+            let style = Color256::RED.on(Color256::BLUE).bold();
+            assert_eq!(
+                Style::transition(style, Style::new()),
+                Color::Default.on(Color::Default).not_bold()
+            );
+        }
+
+        #[test]
+        fn none_is_default() {
+            let style = Style::from(Color::Default).not_bold();
+            assert_eq!(Style::transition(Style::new(), style), Style::new());
+        }
+
+        #[rstest]
+        #[case("bold red on blue", "italic red")]
+        #[case("underline", "bold not underline green")]
+        #[case("reverse on yellow", "reverse on default")]
+        #[case("none", "blink2 conceal #7fff00 on color(42)")]
+        fn round_trip(#[case] from: &str, #[case] to: &str) {
+            let from = from.parse::<Style>().unwrap();
+            let to = to.parse::<Style>().unwrap();
+            let patched = from.patch(Style::transition(from, to));
+            assert_eq!(
+                patched.get_foreground().unwrap_or_default(),
+                to.get_foreground().unwrap_or_default()
+            );
+            assert_eq!(
+                patched.get_background().unwrap_or_default(),
+                to.get_background().unwrap_or_default()
+            );
+            for attr in Attribute::iter() {
+                assert_eq!(patched.is_enabled(attr), to.is_enabled(attr));
+            }
+        }
+    }
+
+    mod undo {
+        use super::*;
+        use crate::Color256;
+
+        #[test]
+        fn empty() {
+            assert_eq!(Style::new().undo(), Style::new());
+        }
+
+        #[test]
+        fn gamut() {
+            let style = Color256::RED.on(Color256::BLUE).bold().not_italic();
+            assert_eq!(style.undo(), Color::Default.on(Color::Default).not_bold());
+        }
+
+        #[test]
+        fn undo_after_patch() {
+            let style = "bold underline red on blue".parse::<Style>().unwrap();
+            let patched = style.patch(style.undo());
+            assert_eq!(
+                patched,
+                Color::Default.on(Color::Default).not_bold().not_underline()
+            );
+        }
+    }
+
+    mod normalize {
+        use super::*;
+
+        #[test]
+        fn empty_base() {
+            let style = "bold not italic red".parse::<Style>().unwrap();
+            assert_eq!(
+                style.normalize(Style::new()),
+                "bold red".parse::<Style>().unwrap()
+            );
+        }
+
+        #[test]
+        fn keeps_needed_disables() {
+            let base = "bold italic".parse::<Style>().unwrap();
+            let style = "not bold not italic not underline"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                style.normalize(base),
+                "not bold not italic".parse::<Style>().unwrap()
+            );
+        }
+
+        #[test]
+        fn base_disabled_attributes() {
+            let base = "not bold".parse::<Style>().unwrap();
+            let style = "not bold".parse::<Style>().unwrap();
+            assert_eq!(style.normalize(base), Style::new());
+        }
+    }
+
+    mod ops {
+        use super::*;
+        use crate::Color256;
+
+        #[test]
+        fn add() {
+            let base = "bold red on blue".parse::<Style>().unwrap();
+            let accent = "italic green".parse::<Style>().unwrap();
+            assert_eq!(base + accent, base.patch(accent));
+            assert_eq!(
+                base + Color256::YELLOW,
+                "bold yellow on blue".parse::<Style>().unwrap()
+            );
+        }
+
+        #[test]
+        fn add_assign() {
+            let mut style = "bold red".parse::<Style>().unwrap();
+            style += "not bold on white".parse::<Style>().unwrap();
+            assert_eq!(style, "not bold red on white".parse::<Style>().unwrap());
+        }
+
+        #[test]
+        fn bitor_and_sub() {
+            let base = "bold red".parse::<Style>().unwrap();
+            let accent = Style::from(Attribute::Underline);
+            assert_eq!(
+                (base + accent - Attribute::Bold) | Attribute::Italic,
+                "not bold italic underline red".parse::<Style>().unwrap()
+            );
+        }
+
+        #[test]
+        fn bitor_assign_and_sub_assign() {
+            let mut style = Style::new();
+            style |= Attribute::Bold | Attribute::Dim;
+            style -= Attribute::Dim;
+            assert_eq!(style, Style::new().bold().not_dim());
+        }
+
+        #[test]
+        fn sum() {
+            let styles = [
+                "bold red".parse::<Style>().unwrap(),
+                "italic on blue".parse::<Style>().unwrap(),
+                "not bold green".parse::<Style>().unwrap(),
+            ];
+            assert_eq!(
+                styles.into_iter().sum::<Style>(),
+                "not bold italic green on blue".parse::<Style>().unwrap()
+            );
+        }
+
+        #[test]
+        fn sum_empty() {
+            assert_eq!(std::iter::empty::<Style>().sum::<Style>(), Style::new());
+        }
+    }
+
+    mod resolve {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use rstest::rstest;
+
+        const FG: Color = Color::Rgb(RgbColor(0xDD, 0xDD, 0xDD));
+        const BG: Color = Color::Rgb(RgbColor(0x11, 0x11, 0x11));
+        const RED: Color = Color::Color256(Color256::RED);
+        const BLUE: Color = Color::Color256(Color256::BLUE);
+
+        #[rstest]
+        #[case("none", FG, BG)]
+        #[case("default on default", FG, BG)]
+        #[case("red", RED, BG)]
+        #[case("on blue", FG, BLUE)]
+        #[case("red on blue", RED, BLUE)]
+        #[case("reverse", BG, FG)]
+        #[case("reverse red on blue", BLUE, RED)]
+        #[case("reverse not reverse red", RED, BG)]
+        #[case("conceal red on blue", BLUE, BLUE)]
+        #[case("conceal red", BG, BG)]
+        #[case("conceal reverse red", RED, RED)]
+        fn resolve(#[case] s: &str, #[case] fg: Color, #[case] bg: Color) {
+            let style = s.parse::<Style>().unwrap();
+            assert_eq!(style.resolve(FG, BG), (fg, bg));
+        }
+    }
+
+    mod contrast {
+        use super::*;
+        use rstest::rstest;
+
+        const FG: RgbColor = RgbColor(0xFF, 0xFF, 0xFF);
+        const BG: RgbColor = RgbColor(0x00, 0x00, 0x00);
+
+        #[rstest]
+        #[case("none", 21.0)]
+        #[case("reverse", 21.0)]
+        #[case("conceal", 1.0)]
+        #[case("on #ffffff", 1.0)]
+        #[case("blue on black", 1.311_703)]
+        #[case("#777777 on default", 4.689_500)]
+        fn contrast_ratio(#[case] s: &str, #[case] ratio: f64) {
+            let style = s.parse::<Style>().unwrap();
+            let r = style.contrast_ratio(&Palette::XTERM, FG, BG);
+            assert!((r - ratio).abs() < 1e-6, "{r} != {ratio}");
+        }
+
+        #[rstest]
+        #[case("none", Readability::Aaa)]
+        #[case("blue", Readability::Insufficient)]
+        #[case("white", Readability::Aaa)]
+        #[case("color(241)", Readability::AaLarge)]
+        #[case("red on white", Readability::Aa)]
+        fn readability(#[case] s: &str, #[case] level: Readability) {
+            let style = s.parse::<Style>().unwrap();
+            assert_eq!(style.readability(&Palette::XTERM, FG, BG), level);
+        }
+    }
+
+    #[cfg(feature = "colored")]
+    mod colored {
+        use super::*;
+        use crate::Color256;
+        use ::colored::{Color as ColoredColor, ColoredString, Colorize};
+
+        #[test]
+        fn to_colored() {
+            let style = "bold blink2 not italic red on #010203"
+                .parse::<Style>()
+                .unwrap();
+            let mut s = ColoredString::from(style);
+            s.input = String::from("text");
+            assert_eq!(s, "text".red().on_truecolor(1, 2, 3).bold());
+        }
+
+        #[test]
+        fn default_colors() {
+            let style = Color::Default.on(Color::Default);
+            assert_eq!(ColoredString::from(style), ColoredString::default());
+        }
+
+        #[test]
+        fn from_colored() {
+            let s = "text"
+                .bright_cyan()
+                .on_color(ColoredColor::AnsiColor(208))
+                .italic();
+            assert_eq!(
+                Style::from(&s),
+                Color256::BRIGHT_CYAN.on(Color256(208)).italic()
+            );
+        }
+
+        #[test]
+        fn round_trip() {
+            let style =
+                "bold dim italic underline blink reverse conceal strike color(208) on bright_white"
+                    .parse::<Style>()
+                    .unwrap();
+            assert_eq!(Style::from(&ColoredString::from(style)), style);
+        }
+
+        #[test]
+        fn attribute_set_round_trip() {
+            let attrs = Attribute::Bold | Attribute::Reverse | Attribute::Conceal;
+            assert_eq!(AttributeSet::from(::colored::Style::from(attrs)), attrs);
+        }
+
+        #[test]
+        fn colors() {
+            for color in [
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(16).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(ColoredColor::try_from(color).unwrap()), color);
+            }
+            assert!(ColoredColor::try_from(Color::Default).is_err());
+        }
+    }
+
+    #[cfg(feature = "console")]
+    mod console {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::console::Color as ConsoleColor;
+
+        #[test]
+        fn to_console() {
+            let style = "bold underline2 not italic red on #010203"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                ::console::Style::from(style),
+                ::console::Style::new().red().on_true_color(1, 2, 3).bold()
+            );
+        }
+
+        #[test]
+        fn to_console_bright() {
+            let style = Color256::BRIGHT_GREEN
+                .on(Color256::BRIGHT_BLUE)
+                .blink2()
+                .conceal();
+            assert_eq!(
+                ::console::Style::from(style),
+                ::console::Style::new()
+                    .green()
+                    .bright()
+                    .on_blue()
+                    .on_bright()
+                    .blink_fast()
+                    .hidden()
+            );
+        }
+
+        #[test]
+        fn to_console_color256() {
+            let style = Color256(208).on(Color::Default);
+            assert_eq!(
+                ::console::Style::from(style),
+                ::console::Style::new().color256(208)
+            );
+        }
+
+        #[test]
+        fn colors() {
+            for color in [
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(16).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(ConsoleColor::try_from(color).unwrap()), color);
+            }
+            assert!(ConsoleColor::try_from(Color::Default).is_err());
+        }
+    }
+
+    #[cfg(feature = "cursive")]
+    mod cursive {
+        use super::*;
+        use crate::Color256;
+        use ::cursive_core::style::{
+            BaseColor, Color as CursiveColor, ColorStyle, ColorType, Effect, EffectStatus,
+        };
+
+        #[test]
+        fn to_cursive() {
+            let style = "bold blink2 not italic not conceal red on default"
+                .parse::<Style>()
+                .unwrap();
+            let cstyle = ::cursive_core::style::Style::from(style);
+            assert_eq!(cstyle.effects.statuses[Effect::Bold], EffectStatus::On);
+            assert_eq!(cstyle.effects.statuses[Effect::Italic], EffectStatus::Off);
+            assert_eq!(
+                cstyle.effects.statuses[Effect::Underline],
+                EffectStatus::InheritParent
+            );
+            assert_eq!(
+                cstyle.color,
+                ColorStyle {
+                    front: ColorType::Color(CursiveColor::Dark(BaseColor::Red)),
+                    back: ColorType::Color(CursiveColor::TerminalDefault),
+                }
+            );
+        }
+
+        #[test]
+        fn to_color_style() {
+            let style = Color256::BRIGHT_CYAN.as_foreground().reverse();
+            assert_eq!(
+                ColorStyle::from(style),
+                ColorStyle {
+                    front: ColorType::Color(CursiveColor::Light(BaseColor::Cyan)),
+                    back: ColorType::InheritParent,
+                }
+            );
+        }
+
+        #[test]
+        fn color256_roundtrip() {
+            for i in 0..=231 {
+                let c = Color::from(Color256(i));
+                assert_eq!(Color::from(CursiveColor::from(c)), c);
+            }
+            assert_eq!(
+                CursiveColor::from(Color256(196)),
+                CursiveColor::RgbLowRes(5, 0, 0)
+            );
+        }
+
+        #[test]
+        fn rgb_low_res_out_of_range() {
+            assert_eq!(
+                Color::from(CursiveColor::RgbLowRes(6, 255, 2)),
+                Color::from(Color256(16 + 36 * 5 + 6 * 5 + 2))
+            );
+        }
+
+        #[test]
+        fn effect_roundtrip() {
+            for attr in Attribute::iter() {
+                if let Ok(effect) = Effect::try_from(attr) {
+                    assert_eq!(Attribute::try_from(effect), Ok(attr));
+                }
+            }
+            assert!(Attribute::try_from(Effect::Simple).is_err());
+        }
+    }
+
+    #[cfg(feature = "egui")]
+    mod egui {
+        use super::*;
+        use ::epaint::text::TextFormat;
+        use ::epaint::{Color32, Stroke};
+
+        #[test]
+        fn to_text_format() {
+            let style = "underline color(208) on #112233".parse::<Style>().unwrap();
+            let fmt = TextFormat::from(style);
+            let orange = Color32::from_rgb(0xFF, 0x87, 0x00);
+            assert_eq!(fmt.color, orange);
+            assert_eq!(fmt.background, Color32::from_rgb(0x11, 0x22, 0x33));
+            assert_eq!(fmt.underline, Stroke::new(1.0, orange));
+            assert_eq!(fmt.strikethrough, Stroke::NONE);
+        }
+
+        #[test]
+        fn default_colors() {
+            let fmt = TextFormat::from(Color::Default.on(Color::Default));
+            assert_eq!(fmt, TextFormat::default());
+            assert!(Color32::try_from(Color::Default).is_err());
+        }
+    }
+
+    #[cfg(feature = "nu-ansi-term")]
+    mod nu_ansi_term {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::nu_ansi_term::Color as NuColor;
+
+        #[test]
+        fn to_nu_ansi_term() {
+            let style = "bold underline2 not italic magenta on #010203"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                ::nu_ansi_term::Style::from(style),
+                NuColor::Magenta.on(NuColor::Rgb(1, 2, 3)).bold()
+            );
+        }
+
+        #[test]
+        fn from_nu_ansi_term() {
+            let style = NuColor::LightPurple
+                .on(NuColor::Default)
+                .italic()
+                .strikethrough()
+                .reset_before_style();
+            assert_eq!(
+                Style::from(style),
+                Color256::BRIGHT_MAGENTA
+                    .on(Color::Default)
+                    .italic()
+                    .strike()
+            );
+        }
+
+        #[test]
+        fn round_trip() {
+            let style =
+                "bold dim italic underline blink reverse conceal strike color(208) on default"
+                    .parse::<Style>()
+                    .unwrap();
+            assert_eq!(Style::from(::nu_ansi_term::Style::from(style)), style);
+        }
+
+        #[test]
+        fn attribute_set_round_trip() {
+            let attrs = Attribute::Dim | Attribute::Blink | Attribute::Strike;
+            let style = ::nu_ansi_term::Style::from(attrs);
+            assert_eq!(Style::from(style), Style::new().enabled_attributes(attrs));
+        }
+
+        #[test]
+        fn colors() {
+            for color in [
+                Color::Default,
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(16).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(NuColor::from(color)), color);
+            }
+        }
+    }
+
+    #[cfg(feature = "owo-colors")]
+    mod owo {
+        use super::*;
+        use crate::{Color256, RgbColor};
+
+        #[test]
+        fn to_owo() {
+            let style = "bold underline2 not italic red on #010203"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                owo_colors::Style::from(style),
+                owo_colors::Style::new().red().on_truecolor(1, 2, 3).bold()
+            );
+        }
+
+        #[test]
+        fn round_trip() {
+            let style = "bold dim italic underline blink blink2 reverse conceal strike color(208) on default"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(Style::from(owo_colors::Style::from(style)), style);
+        }
+
+        #[test]
+        fn from_owo() {
+            let style = owo_colors::Style::new()
+                .bright_cyan()
+                .on_color(owo_colors::CssColors::Tomato)
+                .italic()
+                .strikethrough();
+            assert_eq!(
+                Style::from(style),
+                Color256::BRIGHT_CYAN
+                    .on(RgbColor(255, 99, 71))
+                    .italic()
+                    .strike()
+            );
+        }
+
+        #[test]
+        fn from_plain_owo() {
+            assert_eq!(Style::from(owo_colors::Style::new()), Style::new());
+        }
+
+        #[test]
+        fn dyn_colors() {
+            for color in [
+                Color::Default,
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(16).into(),
+                Color256(255).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(owo_colors::DynColors::from(color)), color);
+            }
+        }
+    }
+
+    #[cfg(feature = "syntect")]
+    mod syntect {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::syntect::highlighting::{
+            Color as SynColor, FontStyle, Style as SynStyle, StyleModifier,
+        };
+
+        const RED: SynColor = SynColor {
+            r: 0x80,
+            g: 0,
+            b: 0,
+            a: 0xFF,
+        };
+
+        #[test]
+        fn to_modifier() {
+            let style = "bold strike not italic red on default"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                StyleModifier::from(style),
+                StyleModifier {
+                    foreground: Some(RED),
+                    background: None,
+                    font_style: Some(FontStyle::BOLD),
+                }
+            );
+        }
+
+        #[test]
+        fn to_modifier_no_attributes() {
+            let style = Style::from(Color256::RED);
+            assert_eq!(
+                StyleModifier::from(style),
+                StyleModifier {
+                    foreground: Some(RED),
+                    background: None,
+                    font_style: None,
+                }
+            );
+        }
+
+        #[test]
+        fn modifier_round_trip() {
+            let style = "bold not italic not underline #010203 on #040506"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(Style::from(StyleModifier::from(style)), style);
+        }
+
+        #[test]
+        fn from_modifier() {
+            let modifier = StyleModifier {
+                foreground: None,
+                background: Some(RED),
+                font_style: Some(FontStyle::ITALIC | FontStyle::UNDERLINE),
+            };
+            assert_eq!(
+                Style::from(modifier),
+                RgbColor(0x80, 0, 0)
+                    .as_background()
+                    .italic()
+                    .underline()
+                    .not_bold()
+            );
+        }
+
+        #[test]
+        fn style_round_trip() {
+            let style = "bold italic #010203 on #040506".parse::<Style>().unwrap();
+            assert_eq!(Style::from(SynStyle::try_from(style).unwrap()), style);
+        }
+
+        #[test]
+        fn to_style_missing_color() {
+            assert!(SynStyle::try_from(Style::from(Color256::RED)).is_err());
+            assert!(SynStyle::try_from(Color256::RED.on(Color::Default)).is_err());
+        }
+    }
+
+    #[cfg(feature = "termcolor")]
+    mod termcolor {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::termcolor::{Color as TermColor, ColorSpec};
+
+        #[test]
+        fn to_color_spec() {
+            let style = "bold underline2 not italic red on #010203"
+                .parse::<Style>()
+                .unwrap();
+            let mut spec = ColorSpec::new();
+            spec.set_fg(Some(TermColor::Red))
+                .set_bg(Some(TermColor::Rgb(1, 2, 3)))
+                .set_bold(true);
+            assert_eq!(ColorSpec::from(style), spec);
+        }
+
+        #[test]
+        fn to_intense_color_spec() {
+            let style = Color256::BRIGHT_GREEN.on(Color256::BLUE).strike();
+            let mut spec = ColorSpec::new();
+            spec.set_fg(Some(TermColor::Green))
+                .set_bg(Some(TermColor::Ansi256(4)))
+                .set_intense(true)
+                .set_strikethrough(true);
+            assert_eq!(ColorSpec::from(style), spec);
+        }
+
+        #[test]
+        fn default_colors() {
+            let style = Color::Default.on(Color::Default);
+            assert_eq!(ColorSpec::from(style), ColorSpec::new());
+        }
+
+        #[test]
+        fn from_intense_color_spec() {
+            let mut spec = ColorSpec::new();
+            spec.set_fg(Some(TermColor::Yellow))
+                .set_bg(Some(TermColor::Ansi256(1)))
+                .set_intense(true)
+                .set_dimmed(true)
+                .set_italic(true);
+            assert_eq!(
+                Style::from(spec),
+                Color256::BRIGHT_YELLOW.on(Color256::RED).dim().italic()
+            );
+        }
+
+        #[test]
+        fn round_trip() {
+            for style in [
+                "bold dim italic underline strike black on white",
+                "bright_magenta on blue",
+                "cyan on bright_black",
+                "color(208) on #abcdef",
+                "underline",
+            ] {
+                let style = style.parse::<Style>().unwrap();
+                assert_eq!(Style::from(ColorSpec::from(style)), style);
+            }
+        }
+
+        #[test]
+        fn colors() {
+            for color in [
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(255).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(TermColor::try_from(color).unwrap()), color);
+            }
+            assert!(TermColor::try_from(Color::Default).is_err());
+        }
+    }
+
+    #[cfg(all(unix, feature = "termion"))]
+    mod termion {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::termion::color::{AnsiValue, Bg, Fg, Reset, Rgb};
+        use ::termion::style as ts;
+
+        #[test]
+        fn empty() {
+            assert_eq!(TermionStyle::from(Style::new()).to_string(), "");
+        }
+
+        #[test]
+        fn colors_and_attributes() {
+            let style = "bold strike frame encircle color(208) on #010203"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                TermionStyle::from(style).to_string(),
+                format!(
+                    "{}{}{}{}{}",
+                    Fg(AnsiValue(208)),
+                    Bg(Rgb(1, 2, 3)),
+                    ts::Bold,
+                    ts::CrossedOut,
+                    ts::Framed
+                )
+            );
+        }
+
+        #[test]
+        fn default_colors() {
+            let style = Color::Default.on(Color::Default);
+            assert_eq!(
+                TermionStyle::from(style).to_string(),
+                format!("{}{}", Fg(Reset), Bg(Reset))
+            );
+        }
+
+        #[test]
+        fn disabled() {
+            let style = "not bold not dim not italic not reverse not conceal"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                TermionStyle::from(style).to_string(),
+                format!("{}{}{}", ts::NoFaint, ts::NoItalic, ts::NoInvert)
+            );
+        }
+
+        #[test]
+        fn termion_colors() {
+            assert_eq!(
+                Fg(Color::from(Color256::RED)).to_string(),
+                Fg(AnsiValue(1)).to_string()
+            );
+            assert_eq!(
+                Bg(Color::from(RgbColor(1, 2, 3))).to_string(),
+                Bg(Rgb(1, 2, 3)).to_string()
+            );
+        }
+    }
+
+    #[cfg(feature = "yansi")]
+    mod yansi {
+        use super::*;
+        use crate::{Color256, RgbColor};
+        use ::yansi::Color as YansiColor;
+
+        #[test]
+        fn to_yansi() {
+            let style = "bold underline2 not italic red on #010203"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(
+                ::yansi::Style::from(style),
+                ::yansi::Style::new().red().on_rgb(1, 2, 3).bold()
+            );
+        }
+
+        #[test]
+        fn from_yansi() {
+            let style = ::yansi::Style::new()
+                .cyan()
+                .bright()
+                .on_fixed(208)
+                .rapid_blink()
+                .invert();
+            assert_eq!(
+                Style::from(style),
+                Color256::BRIGHT_CYAN.on(Color256(208)).blink2().reverse()
+            );
+        }
+
+        #[test]
+        fn from_plain_yansi() {
+            assert_eq!(Style::from(::yansi::Style::new()), Style::new());
+        }
+
+        #[test]
+        fn round_trip() {
+            let style = "bold dim italic underline blink blink2 reverse conceal strike color(208) on default"
+                .parse::<Style>()
+                .unwrap();
+            assert_eq!(Style::from(::yansi::Style::from(style)), style);
+        }
+
+        #[test]
+        fn attribute_set_round_trip() {
+            let attrs = Attribute::Italic | Attribute::Blink2 | Attribute::Reverse;
+            let style = ::yansi::Style::from(attrs);
+            assert_eq!(Style::from(style), Style::new().enabled_attributes(attrs));
+        }
+
+        #[test]
+        fn colors() {
+            for color in [
+                Color::Default,
+                Color256::BLACK.into(),
+                Color256::BRIGHT_WHITE.into(),
+                Color256(16).into(),
+                RgbColor(1, 2, 3).into(),
+            ] {
+                assert_eq!(Color::from(YansiColor::from(color)), color);
+            }
+        }
+    }
+
     mod display {
         use super::*;
         use crate::Color256;
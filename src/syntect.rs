@@ -0,0 +1,184 @@
+//! Building [`syntect`] themes from style strings
+use crate::palette::Palette;
+use crate::rgbcolor::RgbColor;
+use crate::style::{ParseStyleError, Style};
+use syntect::highlighting::{ScopeSelectors, StyleModifier, Theme, ThemeItem, ThemeSettings};
+use syntect::parsing::ParseScopeError;
+use thiserror::Error;
+
+/// Build a [`syntect::highlighting::Theme`] from a sequence of pairs of scope
+/// selectors and style strings.
+///
+/// Each pair is converted to a [`ThemeItem`] whose style is the
+/// [`StyleModifier`] equivalent of the parsed style, with [`Color256`] values
+/// resolved to RGB using `palette`.  Items are added to the theme in the
+/// order in which they are yielded.
+///
+/// The theme's name, author, and settings are left empty; set them on the
+/// returned value as needed.
+///
+/// [`Color256`]: crate::Color256
+///
+/// # Errors
+///
+/// Returns `Err` if a scope selector or style string fails to parse
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Palette, syntect::build_theme};
+/// use syntect::highlighting::{Color, FontStyle};
+///
+/// let theme = build_theme(
+///     [("comment", "italic color(244)"), ("keyword, storage", "bold red")],
+///     &Palette::XTERM,
+/// )
+/// .unwrap();
+/// assert_eq!(theme.scopes.len(), 2);
+/// assert_eq!(theme.scopes[0].style.font_style, Some(FontStyle::ITALIC));
+/// assert_eq!(
+///     theme.scopes[1].style.foreground,
+///     Some(Color { r: 0x80, g: 0, b: 0, a: 0xFF })
+/// );
+/// ```
+pub fn build_theme<I, S, T>(styles: I, palette: &Palette) -> Result<Theme, BuildThemeError>
+where
+    I: IntoIterator<Item = (S, T)>,
+    S: AsRef<str>,
+    T: AsRef<str>,
+{
+    let mut scopes = Vec::new();
+    for (selector, style) in styles {
+        let selector = selector.as_ref();
+        let scope =
+            selector
+                .parse::<ScopeSelectors>()
+                .map_err(|source| BuildThemeError::Scope {
+                    selector: selector.to_owned(),
+                    source,
+                })?;
+        let style = style
+            .as_ref()
+            .parse::<Style>()
+            .map_err(|source| BuildThemeError::Style {
+                selector: selector.to_owned(),
+                source,
+            })?;
+        scopes.push(ThemeItem {
+            scope,
+            style: style_modifier(style, palette),
+        });
+    }
+    Ok(Theme {
+        name: None,
+        author: None,
+        settings: ThemeSettings::default(),
+        scopes,
+    })
+}
+
+/// Convert `style` to a [`StyleModifier`], resolving colors with `palette`
+fn style_modifier(style: Style, palette: &Palette) -> StyleModifier {
+    let resolve = |c: Option<crate::Color>| c?.to_rgb(palette).map(RgbColor::into);
+    StyleModifier {
+        foreground: resolve(style.get_foreground()),
+        background: resolve(style.get_background()),
+        ..StyleModifier::from(style)
+    }
+}
+
+/// Error returned by [`build_theme()`]
+#[derive(Debug, Error)]
+pub enum BuildThemeError {
+    /// A scope selector failed to parse
+    #[error("invalid scope selector {selector:?}")]
+    Scope {
+        /// The invalid scope selector
+        selector: String,
+
+        /// The underlying parse error
+        source: ParseScopeError,
+    },
+
+    /// A style string failed to parse
+    #[error("invalid style string for scope selector {selector:?}")]
+    Style {
+        /// The scope selector that the style string was paired with
+        selector: String,
+
+        /// The underlying parse error
+        source: ParseStyleError,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syntect::highlighting::{Color, FontStyle, Highlighter};
+    use syntect::parsing::Scope;
+
+    #[test]
+    fn highlight() {
+        let mut theme = build_theme(
+            [
+                ("comment", "italic color(244)"),
+                ("keyword", "bold"),
+                ("keyword.control", "red on default"),
+            ],
+            &Palette::XTERM,
+        )
+        .unwrap();
+        theme.settings.foreground = Some(Color::WHITE);
+        theme.settings.background = Some(Color::BLACK);
+        let highlighter = Highlighter::new(&theme);
+        let style = highlighter.style_for_stack(&[
+            Scope::new("source.rust").unwrap(),
+            Scope::new("keyword.control.rust").unwrap(),
+        ]);
+        assert_eq!(
+            style.foreground,
+            Color {
+                r: 0x80,
+                g: 0,
+                b: 0,
+                a: 0xFF
+            }
+        );
+        assert_eq!(style.background, Color::BLACK);
+        assert_eq!(style.font_style, FontStyle::BOLD);
+    }
+
+    #[test]
+    fn custom_palette() {
+        let mut base = Palette::XTERM.base_colors();
+        base[1] = RgbColor(0xCC, 0x24, 0x1D);
+        let theme = build_theme([("string", "red")], &Palette::new(base)).unwrap();
+        assert_eq!(
+            theme.scopes[0].style.foreground,
+            Some(Color {
+                r: 0xCC,
+                g: 0x24,
+                b: 0x1D,
+                a: 0xFF
+            })
+        );
+    }
+
+    #[test]
+    fn bad_style() {
+        let r = build_theme([("comment", "bold on")], &Palette::XTERM);
+        assert!(matches!(
+            r,
+            Err(BuildThemeError::Style {
+                selector,
+                source: ParseStyleError::MissingBackground,
+            }) if selector == "comment"
+        ));
+    }
+
+    #[test]
+    fn bad_scope() {
+        let r = build_theme([("a.b.c.d.e.f.g.h.i", "bold")], &Palette::XTERM);
+        assert!(matches!(r, Err(BuildThemeError::Scope { .. })));
+    }
+}
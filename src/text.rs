@@ -0,0 +1,784 @@
+use crate::style::Style;
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// A string together with a list of styled byte ranges ("spans")
+///
+/// Spans are applied in the order in which they were added: where spans
+/// overlap, the style of a later span is [patched][Style::patch] onto that of
+/// an earlier one, so later spans take precedence.  The style in effect at
+/// any given position can be obtained by iterating over the text's
+/// [segments][StyledText::segments].
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, Style, StyledText};
+///
+/// let mut text = StyledText::new();
+/// text.push_str("Hello, ");
+/// text.push_styled("world", Style::new().bold());
+/// text.push_str("!");
+/// text.stylize(0..5, Color256::RED);
+/// text.stylize(3..9, Style::new().italic());
+///
+/// let segments = text.segments().collect::<Vec<_>>();
+/// assert_eq!(
+///     segments,
+///     [
+///         ("Hel", Style::from(Color256::RED)),
+///         ("lo", Style::from(Color256::RED).italic()),
+///         (", ", Style::new().italic()),
+///         ("wo", Style::new().bold().italic()),
+///         ("rld", Style::new().bold()),
+///         ("!", Style::new()),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct StyledText {
+    text: String,
+    spans: Vec<(Range<usize>, Style)>,
+}
+
+impl StyledText {
+    /// Create a new, empty `StyledText`
+    pub const fn new() -> StyledText {
+        StyledText {
+            text: String::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    /// Create a `StyledText` containing `text` with `style` applied to all of
+    /// it
+    pub fn styled<S: Into<String>, T: Into<Style>>(text: S, style: T) -> StyledText {
+        let mut st = StyledText::from(text.into());
+        st.stylize(0..st.len(), style);
+        st
+    }
+
+    /// Return the text as a string slice, without any styling
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Return the spans in the order in which they are applied
+    pub fn spans(&self) -> &[(Range<usize>, Style)] {
+        &self.spans
+    }
+
+    /// Return the length of the text in bytes
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Test whether the text is empty
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Append unstyled text
+    pub fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    /// Append text with `style` applied to it
+    pub fn push_styled<S: Into<Style>>(&mut self, s: &str, style: S) {
+        let start = self.text.len();
+        self.text.push_str(s);
+        self.stylize(start..self.text.len(), style);
+    }
+
+    /// Append another `StyledText`, preserving its spans
+    ///
+    /// The spans of `other` are applied after the spans of `self`.
+    pub fn append(&mut self, other: StyledText) {
+        let offset = self.text.len();
+        self.text.push_str(&other.text);
+        self.spans.extend(
+            other
+                .spans
+                .into_iter()
+                .map(|(r, style)| ((r.start + offset)..(r.end + offset), style)),
+        );
+    }
+
+    /// Apply `style` to the given byte range of the text, on top of any
+    /// styles already applied there.  If `range` or `style` is empty, this
+    /// does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or if either of its endpoints does
+    /// not lie on a `char` boundary
+    pub fn stylize<S: Into<Style>>(&mut self, range: Range<usize>, style: S) {
+        check_range(&self.text, &range);
+        let style = style.into();
+        if !range.is_empty() && !style.is_empty() {
+            self.spans.push((range, style));
+        }
+    }
+
+    /// Return the given byte range of the text as a new `StyledText`, with
+    /// the spans trimmed to fit
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or if either of its endpoints does
+    /// not lie on a `char` boundary
+    pub fn slice(&self, range: Range<usize>) -> StyledText {
+        check_range(&self.text, &range);
+        let text = self.text[range.clone()].to_owned();
+        let spans = self
+            .spans
+            .iter()
+            .filter_map(|(r, style)| {
+                let start = r.start.max(range.start);
+                let end = r.end.min(range.end);
+                (start < end).then(|| ((start - range.start)..(end - range.start), *style))
+            })
+            .collect();
+        StyledText { text, spans }
+    }
+
+    /// Return an iterator over the maximal substrings of the text that each
+    /// have a single effective style, paired with those styles
+    ///
+    /// Each segment's style is the result of patching together the styles of
+    /// all spans covering it, in order.  Unstyled text is paired with an empty
+    /// `Style`.  Empty text yields no segments.
+    pub fn segments(&self) -> Segments<'_> {
+        // Sweep over the span boundaries in order, keeping track of which
+        // spans are active.  The active set is ordered by span index so that
+        // styles are patched together in the order the spans were added.
+        let mut events = Vec::with_capacity(self.spans.len() * 2);
+        for (i, (r, _)) in self.spans.iter().enumerate() {
+            events.push((r.start, i));
+            events.push((r.end, i));
+        }
+        events.sort_unstable();
+        let mut events = events.into_iter().peekable();
+        let mut active = BTreeSet::new();
+        let mut segments: Vec<(Range<usize>, Style)> = Vec::new();
+        let mut start = 0;
+        while start < self.text.len() {
+            while let Some((_, i)) = events.next_if(|&(pos, _)| pos <= start) {
+                // Each span has exactly two events, and its start comes
+                // before its end, so the second event removes it.
+                if !active.insert(i) {
+                    active.remove(&i);
+                }
+            }
+            let end = events.peek().map_or(self.text.len(), |&(pos, _)| pos);
+            let style = active
+                .iter()
+                .fold(Style::new(), |acc, &i| acc.patch(self.spans[i].1));
+            match segments.last_mut() {
+                Some((prev, prev_style)) if *prev_style == style => prev.end = end,
+                _ => segments.push((start..end, style)),
+            }
+            start = end;
+        }
+        Segments {
+            text: &self.text,
+            inner: segments.into_iter(),
+        }
+    }
+
+    /// Split the `StyledText` into its text and spans
+    pub fn into_parts(self) -> (String, Vec<(Range<usize>, Style)>) {
+        (self.text, self.spans)
+    }
+}
+
+fn check_range(text: &str, range: &Range<usize>) {
+    assert!(
+        range.start <= range.end && range.end <= text.len(),
+        "range {range:?} out of bounds for text of length {}",
+        text.len()
+    );
+    assert!(
+        text.is_char_boundary(range.start) && text.is_char_boundary(range.end),
+        "range {range:?} does not lie on char boundaries"
+    );
+}
+
+impl From<String> for StyledText {
+    fn from(text: String) -> StyledText {
+        StyledText {
+            text,
+            spans: Vec::new(),
+        }
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(text: &str) -> StyledText {
+        StyledText::from(text.to_owned())
+    }
+}
+
+impl<S: AsRef<str>, T: Into<Style>> FromIterator<(S, T)> for StyledText {
+    /// Concatenate a sequence of strings, each styled with the accompanying
+    /// style
+    fn from_iter<I: IntoIterator<Item = (S, T)>>(iter: I) -> StyledText {
+        let mut text = StyledText::new();
+        text.extend(iter);
+        text
+    }
+}
+
+impl<S: AsRef<str>, T: Into<Style>> Extend<(S, T)> for StyledText {
+    fn extend<I: IntoIterator<Item = (S, T)>>(&mut self, iter: I) {
+        for (s, style) in iter {
+            self.push_styled(s.as_ref(), style);
+        }
+    }
+}
+
+impl std::ops::Add<StyledText> for StyledText {
+    type Output = StyledText;
+
+    fn add(mut self, rhs: StyledText) -> StyledText {
+        self.append(rhs);
+        self
+    }
+}
+
+impl std::ops::AddAssign<StyledText> for StyledText {
+    fn add_assign(&mut self, rhs: StyledText) {
+        self.append(rhs);
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl<'a> From<&'a StyledText> for ratatui_core::text::Line<'a> {
+    /// Convert a `StyledText` to a [`ratatui_core::text::Line`] containing one
+    /// [`Span`][ratatui_core::text::Span] per [segment][StyledText::segments]
+    ///
+    /// The resulting line's own style is empty, and any newlines in the text
+    /// are kept as-is; to split the text into lines, convert it to a
+    /// [`ratatui_core::text::Text`] instead.
+    ///
+    /// # Data Loss
+    ///
+    /// Styles lose the same information as when converting a [`Style`] to a
+    /// [`ratatui_core::style::Style`].
+    fn from(value: &'a StyledText) -> ratatui_core::text::Line<'a> {
+        ratatui_core::text::Line::from(
+            value
+                .segments()
+                .map(|(s, style)| ratatui_core::text::Span::styled(s, style))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<StyledText> for ratatui_core::text::Line<'static> {
+    /// Convert a `StyledText` to a [`ratatui_core::text::Line`] containing one
+    /// [`Span`][ratatui_core::text::Span] per [segment][StyledText::segments]
+    ///
+    /// See the conversion from `&StyledText` for details.
+    fn from(value: StyledText) -> ratatui_core::text::Line<'static> {
+        ratatui_core::text::Line::from(
+            value
+                .segments()
+                .map(|(s, style)| ratatui_core::text::Span::styled(s.to_owned(), style))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl<'a> From<&'a StyledText> for ratatui_core::text::Text<'a> {
+    /// Convert a `StyledText` to a [`ratatui_core::text::Text`], splitting it
+    /// into [`Line`][ratatui_core::text::Line]s at each `'\n'` character
+    ///
+    /// Each line contains one [`Span`][ratatui_core::text::Span] per (part of
+    /// a) [segment][StyledText::segments] on that line.  The styles of the
+    /// resulting text and its lines are empty.  A trailing newline does not
+    /// produce an empty final line.
+    ///
+    /// # Data Loss
+    ///
+    /// Styles lose the same information as when converting a [`Style`] to a
+    /// [`ratatui_core::style::Style`].
+    fn from(value: &'a StyledText) -> ratatui_core::text::Text<'a> {
+        let mut lines = vec![ratatui_core::text::Line::default()];
+        for (s, style) in value.segments() {
+            for (i, piece) in s.split('\n').enumerate() {
+                if i > 0 {
+                    lines.push(ratatui_core::text::Line::default());
+                }
+                if !piece.is_empty() {
+                    if let Some(line) = lines.last_mut() {
+                        line.spans
+                            .push(ratatui_core::text::Span::styled(piece, style));
+                    }
+                }
+            }
+        }
+        if value.is_empty() || value.as_str().ends_with('\n') {
+            lines.pop();
+        }
+        ratatui_core::text::Text::from(lines)
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<StyledText> for ratatui_core::text::Text<'static> {
+    /// Convert a `StyledText` to a [`ratatui_core::text::Text`], splitting it
+    /// into [`Line`][ratatui_core::text::Line]s at each `'\n'` character
+    ///
+    /// See the conversion from `&StyledText` for details.
+    fn from(value: StyledText) -> ratatui_core::text::Text<'static> {
+        let lines = ratatui_core::text::Text::from(&value)
+            .lines
+            .into_iter()
+            .map(|line| {
+                ratatui_core::text::Line::from(
+                    line.spans
+                        .into_iter()
+                        .map(|span| {
+                            ratatui_core::text::Span::styled(span.content.into_owned(), span.style)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        ratatui_core::text::Text::from(lines)
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<&ratatui_core::text::Span<'_>> for StyledText {
+    /// Convert a [`ratatui_core::text::Span`] to a `StyledText` whose entire
+    /// text is styled with the span's style
+    ///
+    /// # Data Loss
+    ///
+    /// Underline color is discarded during conversion.
+    fn from(value: &ratatui_core::text::Span<'_>) -> StyledText {
+        StyledText::styled(value.content.as_ref(), value.style)
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<&ratatui_core::text::Line<'_>> for StyledText {
+    /// Convert a [`ratatui_core::text::Line`] to a `StyledText` containing
+    /// the line's spans
+    ///
+    /// # Data Loss
+    ///
+    /// The line's own style and alignment are discarded during conversion; use
+    /// [`StyledText::from_ratatui_line()`] to obtain the line style as well.
+    ///
+    /// Underline colors are discarded during conversion.
+    fn from(value: &ratatui_core::text::Line<'_>) -> StyledText {
+        let mut text = StyledText::new();
+        push_spans(&mut text, value);
+        text
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl From<&ratatui_core::text::Text<'_>> for StyledText {
+    /// Convert a [`ratatui_core::text::Text`] to a `StyledText` containing
+    /// the spans of its lines, joined with `'\n'` characters
+    ///
+    /// # Data Loss
+    ///
+    /// The styles and alignments of the text and its lines are discarded
+    /// during conversion; use [`StyledText::from_ratatui_text()`] to obtain
+    /// the line styles as well.
+    ///
+    /// Underline colors are discarded during conversion.
+    fn from(value: &ratatui_core::text::Text<'_>) -> StyledText {
+        StyledText::from_ratatui_text(value).0
+    }
+}
+
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+impl StyledText {
+    /// Convert a [`ratatui_core::text::Line`] to a `StyledText` containing
+    /// the line's spans, paired with the line's own style
+    ///
+    /// The line style is kept separate from the span styles, so that
+    /// converting the result back with [`StyledText::to_ratatui_line()`]
+    /// reproduces the original styling.
+    ///
+    /// # Data Loss
+    ///
+    /// The line's alignment is discarded during conversion.
+    ///
+    /// Underline colors are discarded during conversion.
+    pub fn from_ratatui_line(line: &ratatui_core::text::Line<'_>) -> (StyledText, Style) {
+        (StyledText::from(line), Style::from(line.style))
+    }
+
+    /// Convert a `StyledText` to a [`ratatui_core::text::Line`] with the
+    /// given line style, containing one [`Span`][ratatui_core::text::Span]
+    /// per [segment][StyledText::segments]
+    ///
+    /// # Data Loss
+    ///
+    /// Styles lose the same information as when converting a [`Style`] to a
+    /// [`ratatui_core::style::Style`].
+    pub fn to_ratatui_line(&self, style: Style) -> ratatui_core::text::Line<'_> {
+        ratatui_core::text::Line::from(self).style(style)
+    }
+
+    /// Convert a [`ratatui_core::text::Text`] to a `StyledText` containing
+    /// the spans of its lines, joined with `'\n'` characters, paired with
+    /// the style of each line
+    ///
+    /// The line styles are kept separate from the span styles, so that
+    /// converting the result back with [`StyledText::to_ratatui_text()`]
+    /// reproduces the original lines.
+    ///
+    /// # Data Loss
+    ///
+    /// The style and alignment of the text as a whole and the alignments of
+    /// its lines are discarded during conversion.
+    ///
+    /// Underline colors are discarded during conversion.
+    pub fn from_ratatui_text(text: &ratatui_core::text::Text<'_>) -> (StyledText, Vec<Style>) {
+        let mut st = StyledText::new();
+        let mut styles = Vec::with_capacity(text.lines.len());
+        for (i, line) in text.lines.iter().enumerate() {
+            if i > 0 {
+                st.push_str("\n");
+            }
+            push_spans(&mut st, line);
+            styles.push(Style::from(line.style));
+        }
+        (st, styles)
+    }
+
+    /// Convert a `StyledText` to a [`ratatui_core::text::Text`], splitting it
+    /// into [`Line`][ratatui_core::text::Line]s at each `'\n'` character and
+    /// giving each line the corresponding style from `line_styles`
+    ///
+    /// Lines without a corresponding entry in `line_styles` have empty
+    /// styles.  If there are more line styles than lines in the text, empty
+    /// lines are added at the end so that every style is used.  Otherwise,
+    /// lines are split as when converting a `&StyledText` to a
+    /// [`ratatui_core::text::Text`].
+    ///
+    /// # Data Loss
+    ///
+    /// Styles lose the same information as when converting a [`Style`] to a
+    /// [`ratatui_core::style::Style`].
+    pub fn to_ratatui_text(&self, line_styles: &[Style]) -> ratatui_core::text::Text<'_> {
+        let mut text = ratatui_core::text::Text::from(self);
+        if text.lines.len() < line_styles.len() {
+            text.lines
+                .resize(line_styles.len(), ratatui_core::text::Line::default());
+        }
+        for (line, &style) in text.lines.iter_mut().zip(line_styles) {
+            line.style = style.into();
+        }
+        text
+    }
+}
+
+/// Append the contents of a `ratatui` `Line` to `text`, styled with the
+/// styles of the line's spans
+#[cfg(feature = "ratatui")]
+fn push_spans(text: &mut StyledText, line: &ratatui_core::text::Line<'_>) {
+    for span in &line.spans {
+        text.push_styled(&span.content, span.style);
+    }
+}
+
+/// An iterator over the segments of a [`StyledText`], each paired with its
+/// effective style
+///
+/// This `struct` is returned by [`StyledText::segments()`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    text: &'a str,
+    inner: std::vec::IntoIter<(Range<usize>, Style)>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = (&'a str, Style);
+
+    fn next(&mut self) -> Option<(&'a str, Style)> {
+        let (r, style) = self.inner.next()?;
+        Some((&self.text[r], style))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Segments<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (r, style) = self.inner.next_back()?;
+        Some((&self.text[r], style))
+    }
+}
+
+impl ExactSizeIterator for Segments<'_> {}
+
+impl std::iter::FusedIterator for Segments<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color256;
+
+    #[test]
+    fn empty() {
+        let text = StyledText::new();
+        assert!(text.is_empty());
+        assert_eq!(text.segments().next(), None);
+    }
+
+    #[test]
+    fn unstyled() {
+        let text = StyledText::from("plain");
+        assert_eq!(
+            text.segments().collect::<Vec<_>>(),
+            [("plain", Style::new())]
+        );
+    }
+
+    #[test]
+    fn merge_equal_neighbors() {
+        let mut text = StyledText::new();
+        text.push_styled("foo", Style::new().bold());
+        text.push_styled("bar", Style::new().bold());
+        assert_eq!(text.spans().len(), 2);
+        assert_eq!(
+            text.segments().collect::<Vec<_>>(),
+            [("foobar", Style::new().bold())]
+        );
+    }
+
+    #[test]
+    fn overlay_disables() {
+        let mut text = StyledText::styled("abcdef", Style::new().bold());
+        text.stylize(2..4, Style::new().not_bold().underline());
+        assert_eq!(
+            text.segments().collect::<Vec<_>>(),
+            [
+                ("ab", Style::new().bold()),
+                ("cd", Style::new().not_bold().underline()),
+                ("ef", Style::new().bold()),
+            ]
+        );
+    }
+
+    #[test]
+    fn slice() {
+        let mut text = StyledText::from("0123456789");
+        text.stylize(2..5, Color256::RED);
+        text.stylize(4..8, Color256::BLUE);
+        text.stylize(8..10, Style::new().bold());
+        let sliced = text.slice(3..7);
+        assert_eq!(sliced.as_str(), "3456");
+        assert_eq!(
+            sliced.spans(),
+            [
+                (0..2, Style::from(Color256::RED)),
+                (1..4, Style::from(Color256::BLUE)),
+            ]
+        );
+    }
+
+    #[test]
+    fn append() {
+        let mut text = StyledText::styled("ab", Color256::RED);
+        text += StyledText::styled("cd", Color256::GREEN);
+        assert_eq!(
+            text.spans(),
+            [
+                (0..2, Style::from(Color256::RED)),
+                (2..4, Style::from(Color256::GREEN)),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let text = [("a", Style::new().bold()), ("b", Style::new())]
+            .into_iter()
+            .collect::<StyledText>();
+        assert_eq!(text.as_str(), "ab");
+        assert_eq!(text.spans(), [(0..1, Style::new().bold())]);
+    }
+
+    #[test]
+    fn segments_patch_in_span_order() {
+        let mut text = StyledText::from("abcdef");
+        text.stylize(2..6, Color256::RED);
+        text.stylize(0..3, Color256::BLUE);
+        text.stylize(1..5, Style::new().bold());
+        text.stylize(4..6, Color256::GREEN);
+        assert_eq!(
+            text.segments().collect::<Vec<_>>(),
+            [
+                ("a", Style::from(Color256::BLUE)),
+                ("bc", Style::from(Color256::BLUE).bold()),
+                ("d", Style::from(Color256::RED).bold()),
+                ("e", Style::from(Color256::GREEN).bold()),
+                ("f", Style::from(Color256::GREEN)),
+            ]
+        );
+    }
+
+    #[test]
+    fn segments_rev() {
+        let mut text = StyledText::from("abc");
+        text.stylize(1..2, Style::new().italic());
+        assert_eq!(
+            text.segments().rev().map(|(s, _)| s).collect::<Vec<_>>(),
+            ["c", "b", "a"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "char boundaries")]
+    fn stylize_not_char_boundary() {
+        let mut text = StyledText::from("é");
+        text.stylize(0..1, Style::new().bold());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn stylize_out_of_bounds() {
+        let mut text = StyledText::from("abc");
+        text.stylize(1..4, Style::new().bold());
+    }
+
+    #[cfg(feature = "ratatui")]
+    mod ratatui {
+        use super::*;
+        use ::ratatui_core::style::{Color as RColor, Modifier, Style as RStyle, Stylize};
+        use ::ratatui_core::text::{Line, Span, Text};
+
+        #[test]
+        fn to_line() {
+            let mut text = StyledText::from("foo bar");
+            text.stylize(0..3, Style::new().bold());
+            let line = Line::from(&text);
+            assert_eq!(line.style, RStyle::new());
+            assert_eq!(line.spans, [Span::raw("foo").bold(), Span::raw(" bar")]);
+        }
+
+        #[test]
+        fn to_text() {
+            let mut text = StyledText::from("ab\ncd\n\nef\n");
+            text.stylize(1..4, Color256::RED);
+            let rtext = Text::from(text);
+            assert_eq!(
+                rtext.lines,
+                [
+                    Line::from(vec![Span::raw("a"), Span::raw("b").fg(RColor::Indexed(1))]),
+                    Line::from(vec![Span::raw("c").fg(RColor::Indexed(1)), Span::raw("d")]),
+                    Line::default(),
+                    Line::from("ef"),
+                ]
+            );
+        }
+
+        #[test]
+        fn empty_to_text() {
+            assert!(Text::from(&StyledText::new()).lines.is_empty());
+        }
+
+        #[test]
+        fn from_line() {
+            let line = Line::from(vec![Span::raw("foo").not_bold(), Span::raw("bar").italic()])
+                .bold()
+                .centered();
+            let text = StyledText::from(&line);
+            assert_eq!(text.as_str(), "foobar");
+            assert_eq!(
+                text.spans(),
+                [
+                    (0..3, Style::new().not_bold()),
+                    (3..6, Style::new().italic()),
+                ]
+            );
+        }
+
+        #[test]
+        fn line_roundtrip() {
+            let line = Line::from(vec![
+                Span::raw("foo").not_bold(),
+                Span::raw("bar").italic().fg(RColor::Indexed(1)),
+                Span::raw("baz"),
+            ])
+            .bold()
+            .bg(RColor::Indexed(4));
+            let (text, style) = StyledText::from_ratatui_line(&line);
+            assert_eq!(
+                style,
+                Style::new().bold().background(Some(Color256::BLUE.into()))
+            );
+            assert_eq!(
+                text.spans(),
+                [
+                    (0..3, Style::new().not_bold()),
+                    (3..6, Style::from(Color256::RED).italic()),
+                ]
+            );
+            let line2 = text.to_ratatui_line(style);
+            assert_eq!(line2.style, line.style);
+            assert_eq!(line2.spans, line.spans);
+        }
+
+        #[test]
+        fn from_text() {
+            let rtext = Text::from(vec![
+                Line::from("ab").fg(RColor::Blue),
+                Line::from(Span::raw("cd").add_modifier(Modifier::UNDERLINED)),
+            ])
+            .bg(RColor::Black);
+            let text = StyledText::from(&rtext);
+            assert_eq!(text.as_str(), "ab\ncd");
+            assert_eq!(text.spans(), [(3..5, Style::new().underline())]);
+        }
+
+        #[test]
+        fn text_roundtrip() {
+            let rtext = Text::from(vec![
+                Line::from(vec![Span::raw("a").bold(), Span::raw("b")]).fg(RColor::Indexed(4)),
+                Line::from(Span::raw("cd").underlined()).italic(),
+                Line::default().bg(RColor::Indexed(1)),
+            ]);
+            let (text, styles) = StyledText::from_ratatui_text(&rtext);
+            assert_eq!(text.as_str(), "ab\ncd\n");
+            assert_eq!(
+                styles,
+                [
+                    Style::from(Color256::BLUE),
+                    Style::new().italic(),
+                    Style::new().background(Some(Color256::RED.into())),
+                ]
+            );
+            let rtext2 = text.to_ratatui_text(&styles);
+            assert_eq!(rtext2.lines, rtext.lines);
+        }
+
+        #[test]
+        fn span() {
+            let text = StyledText::from(&Span::raw("x").red());
+            assert_eq!(text.spans(), [(0..1, Style::from(Color256::RED))]);
+        }
+    }
+}
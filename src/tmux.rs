@@ -0,0 +1,335 @@
+use crate::attributes::{Attribute, AttributeSet};
+use crate::color::Color;
+use crate::color256::Color256;
+use crate::rgbcolor::RgbColor;
+use crate::style::Style;
+use crate::util::strip_nocase_prefix;
+use std::fmt;
+use thiserror::Error;
+
+/// The names of the basic colors in tmux's style syntax
+static COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// tmux's attribute names, along with the attributes they are parsed as.  The
+/// first name for each attribute is the one used when displaying.
+static TMUX_ATTRIBUTES: [(&str, Attribute); 14] = [
+    ("bold", Attribute::Bold),
+    ("bright", Attribute::Bold),
+    ("dim", Attribute::Dim),
+    ("italics", Attribute::Italic),
+    ("underscore", Attribute::Underline),
+    ("curly-underscore", Attribute::Underline),
+    ("dotted-underscore", Attribute::Underline),
+    ("dashed-underscore", Attribute::Underline),
+    ("blink", Attribute::Blink),
+    ("reverse", Attribute::Reverse),
+    ("hidden", Attribute::Conceal),
+    ("strikethrough", Attribute::Strike),
+    ("double-underscore", Attribute::Underline2),
+    ("overline", Attribute::Overline),
+];
+
+impl Style {
+    /// Parse a style in the syntax used by tmux options like `status-style`,
+    /// such as `fg=colour208,bg=default,bold`
+    ///
+    /// A tmux style is a list of items separated by commas and/or whitespace,
+    /// each of which is one of the following (case-insensitive):
+    ///
+    /// - `fg=COLOR` or `bg=COLOR`, where `COLOR` is `default` or `terminal`
+    ///   (both of which become [`Color::Default`]), one of the eight basic
+    ///   color names, a basic color name prefixed with `bright` (e.g.,
+    ///   `brightred`), `colourN` or `colorN` for `N` from 0 to 255, or a hex
+    ///   RGB color of the form `#rrggbb`
+    ///
+    /// - An attribute: `bold` or `bright`, `dim`, `italics`, `underscore`,
+    ///   `double-underscore`, `curly-underscore`, `dotted-underscore`,
+    ///   `dashed-underscore`, `blink`, `reverse`, `hidden`, `strikethrough`,
+    ///   or `overline`.  An attribute prefixed with `no` (e.g., `nobold`) is
+    ///   disabled.  Curly, dotted, and dashed underscores are parsed as
+    ///   [`Attribute::Underline`].
+    ///
+    /// - `none`, which disables all attributes
+    ///
+    /// - `default`, which sets both colors to `Color::Default` and disables
+    ///   all attributes
+    ///
+    /// The `us` (underscore color) item is accepted and ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an item or color is not recognized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Color, Color256, Style};
+    ///
+    /// assert_eq!(
+    ///     Style::parse_tmux_style("fg=colour208,bg=default,bold").unwrap(),
+    ///     Color256(208).on(Color::Default).bold(),
+    /// );
+    /// assert_eq!(
+    ///     Style::parse_tmux_style("fg=brightwhite bg=#303030 noitalics").unwrap(),
+    ///     "bright_white on #303030 not italic".parse::<Style>().unwrap(),
+    /// );
+    /// ```
+    pub fn parse_tmux_style(s: &str) -> Result<Style, ParseTmuxStyleError> {
+        let all_attrs = TMUX_ATTRIBUTES
+            .iter()
+            .fold(AttributeSet::EMPTY, |acc, &(_, attr)| acc | attr);
+        let mut style = Style::new();
+        for item in s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+        {
+            if let Some((key, value)) = item.split_once('=') {
+                let bad_color = || ParseTmuxStyleError::Color(value.to_owned());
+                if key.eq_ignore_ascii_case("fg") {
+                    style = style.foreground(Some(parse_tmux_color(value).ok_or_else(bad_color)?));
+                } else if key.eq_ignore_ascii_case("bg") {
+                    style = style.background(Some(parse_tmux_color(value).ok_or_else(bad_color)?));
+                } else if key.eq_ignore_ascii_case("us") {
+                    parse_tmux_color(value).ok_or_else(bad_color)?;
+                } else {
+                    return Err(ParseTmuxStyleError::Item(item.to_owned()));
+                }
+            } else if item.eq_ignore_ascii_case("none") {
+                style = style.disable(all_attrs);
+            } else if item.eq_ignore_ascii_case("default") {
+                style = Color::Default.on(Color::Default).disable(all_attrs);
+            } else if let Some(attr) = parse_tmux_attribute(item) {
+                style = style.enable(attr);
+            } else if let Some(attr) =
+                strip_nocase_prefix(item, "no").and_then(parse_tmux_attribute)
+            {
+                style = style.disable(attr);
+            } else {
+                return Err(ParseTmuxStyleError::Item(item.to_owned()));
+            }
+        }
+        Ok(style)
+    }
+
+    /// Return a value that displays the style in the syntax used by tmux; see
+    /// [`TmuxStyle`]
+    pub fn display_tmux(self) -> TmuxStyle {
+        TmuxStyle(self)
+    }
+}
+
+fn parse_tmux_color(value: &str) -> Option<Color> {
+    if value.eq_ignore_ascii_case("default") || value.eq_ignore_ascii_case("terminal") {
+        Some(Color::Default)
+    } else if value.starts_with('#') {
+        RgbColor::parse_str(value).map(Color::from)
+    } else if let Some(index) =
+        strip_nocase_prefix(value, "colour").or_else(|| strip_nocase_prefix(value, "color"))
+    {
+        index.parse::<u8>().ok().map(|n| Color256(n).into())
+    } else {
+        let (name, offset) = match strip_nocase_prefix(value, "bright") {
+            Some(name) => (name, 8),
+            None => (value, 0),
+        };
+        let index = COLOR_NAMES
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(name))?;
+        let index = u8::try_from(index).ok()? + offset;
+        Some(Color256(index).into())
+    }
+}
+
+fn parse_tmux_attribute(name: &str) -> Option<Attribute> {
+    TMUX_ATTRIBUTES
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|&(_, attr)| attr)
+}
+
+/// A wrapper around a [`Style`] that displays it in the syntax used by tmux
+///
+/// Enabled attributes are written first, followed by disabled attributes
+/// (with a `no` prefix) and then the `fg` and `bg` colors, all separated by
+/// commas.  [`Color256`] colors 0 through 15 are written as color names and
+/// other `Color256` colors as `colourN`.  An empty style is written as an
+/// empty string.  The output can be parsed back with
+/// [`Style::parse_tmux_style()`].
+///
+/// # Data Loss
+///
+/// [`Attribute::Blink2`] is written as `blink`.  The following attributes are
+/// not supported by tmux and are omitted:
+///
+/// - [`Attribute::Frame`]
+/// - [`Attribute::Encircle`]
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color, Color256, Style};
+///
+/// let style = Color256(208).on(Color::Default).bold().not_italic();
+/// assert_eq!(
+///     style.display_tmux().to_string(),
+///     "bold,noitalics,fg=colour208,bg=default",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TmuxStyle(Style);
+
+impl TmuxStyle {
+    /// Return the wrapped `Style`
+    pub const fn style(self) -> Style {
+        self.0
+    }
+}
+
+impl From<Style> for TmuxStyle {
+    fn from(value: Style) -> TmuxStyle {
+        TmuxStyle(value)
+    }
+}
+
+impl fmt::Display for TmuxStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.0;
+        let mut items = Vec::new();
+        let tmux_attrs = |attrs: AttributeSet| {
+            let attrs = if attrs.contains(Attribute::Blink2) {
+                (attrs - Attribute::Blink2) | Attribute::Blink
+            } else {
+                attrs
+            };
+            attrs.into_iter().filter_map(|attr| {
+                TMUX_ATTRIBUTES
+                    .iter()
+                    .find(|&&(_, a)| a == attr)
+                    .map(|&(name, _)| name)
+            })
+        };
+        items.extend(tmux_attrs(style.get_enabled_attributes()).map(String::from));
+        items.extend(tmux_attrs(style.get_disabled_attributes()).map(|name| format!("no{name}")));
+        if let Some(fg) = style.get_foreground() {
+            items.push(format!("fg={}", tmux_color_name(fg)));
+        }
+        if let Some(bg) = style.get_background() {
+            items.push(format!("bg={}", tmux_color_name(bg)));
+        }
+        write!(f, "{}", items.join(","))
+    }
+}
+
+fn tmux_color_name(color: Color) -> String {
+    match color {
+        Color::Default => String::from("default"),
+        Color::Color256(Color256(n @ 0..=7)) => COLOR_NAMES[usize::from(n)].to_owned(),
+        Color::Color256(Color256(n @ 8..=15)) => {
+            format!("bright{}", COLOR_NAMES[usize::from(n - 8)])
+        }
+        Color::Color256(Color256(n)) => format!("colour{n}"),
+        Color::Rgb(rgb) => rgb.to_string(),
+    }
+}
+
+/// Error returned when parsing a tmux style fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseTmuxStyleError {
+    /// An item was not recognized
+    #[error("unrecognized item in tmux style: {0:?}")]
+    Item(
+        /// The unrecognized item
+        String,
+    ),
+
+    /// A color was not recognized
+    #[error("invalid color in tmux style: {0:?}")]
+    Color(
+        /// The invalid color
+        String,
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", Style::new())]
+    #[case("fg=red", Color256::RED.as_foreground())]
+    #[case("fg=colour208,bg=default,bold", Color256(208).on(Color::Default).bold())]
+    #[case("FG=Color17 bg=terminal", Color256(17).on(Color::Default))]
+    #[case("bg=brightblack, fg=#AbCdEf", RgbColor(0xab, 0xcd, 0xef).on(Color256::BRIGHT_BLACK))]
+    #[case("bright", Style::new().bold())]
+    #[case("italics,noitalics", Style::new().not_italic())]
+    #[case("hidden overline double-underscore", Style::new().conceal().overline().underline2())]
+    #[case("curly-underscore", Style::new().underline())]
+    #[case("fg=blue,us=red", Color256::BLUE.as_foreground())]
+    #[case(
+        "bold,none,dim",
+        Style::new()
+            .dim()
+            .not_bold()
+            .not_italic()
+            .not_underline()
+            .not_underline2()
+            .not_blink()
+            .not_reverse()
+            .not_conceal()
+            .not_strike()
+            .not_overline()
+    )]
+    #[case(
+        "fg=red,default",
+        Color::Default
+            .on(Color::Default)
+            .not_bold()
+            .not_dim()
+            .not_italic()
+            .not_underline()
+            .not_underline2()
+            .not_blink()
+            .not_reverse()
+            .not_conceal()
+            .not_strike()
+            .not_overline()
+    )]
+    fn parse(#[case] s: &str, #[case] style: Style) {
+        assert_eq!(Style::parse_tmux_style(s), Ok(style));
+    }
+
+    #[rstest]
+    #[case("fg=colour256", ParseTmuxStyleError::Color("colour256".into()))]
+    #[case("bg=bright", ParseTmuxStyleError::Color("bright".into()))]
+    #[case("fg=#fff", ParseTmuxStyleError::Color("#fff".into()))]
+    #[case("underline", ParseTmuxStyleError::Item("underline".into()))]
+    #[case("align=centre", ParseTmuxStyleError::Item("align=centre".into()))]
+    #[case("not", ParseTmuxStyleError::Item("not".into()))]
+    fn parse_error(#[case] s: &str, #[case] err: ParseTmuxStyleError) {
+        assert_eq!(Style::parse_tmux_style(s), Err(err));
+    }
+
+    #[rstest]
+    #[case(Style::new(), "")]
+    #[case(Color256::BRIGHT_GREEN.as_foreground(), "fg=brightgreen")]
+    #[case(RgbColor(1, 2, 3).as_background().blink2(), "blink,bg=#010203")]
+    #[case(Style::new().frame().not_encircle(), "")]
+    #[case(
+        Style::new().underline2().not_underline().not_bold(),
+        "double-underscore,nobold,nounderscore"
+    )]
+    fn display(#[case] style: Style, #[case] s: &str) {
+        assert_eq!(style.display_tmux().to_string(), s);
+    }
+
+    #[rstest]
+    #[case(Color256(100).on(Color256::WHITE).italic().not_strike())]
+    #[case(Color::Default.as_foreground().reverse().conceal().overline())]
+    fn roundtrip(#[case] style: Style) {
+        let s = style.display_tmux().to_string();
+        assert_eq!(Style::parse_tmux_style(&s), Ok(style));
+    }
+}
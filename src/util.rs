@@ -1,8 +1,91 @@
+use crate::rgbcolor::RgbColor;
+
+/// A selection of common X11 color names (lowercase, without spaces) along
+/// with their RGB values.  These are the X11 names that Vim recognizes as GUI
+/// colors on every platform.
+static X11_COLORS: [(&str, RgbColor); 32] = [
+    ("black", RgbColor(0x00, 0x00, 0x00)),
+    ("blue", RgbColor(0x00, 0x00, 0xFF)),
+    ("brown", RgbColor(0xA5, 0x2A, 0x2A)),
+    ("cyan", RgbColor(0x00, 0xFF, 0xFF)),
+    ("darkblue", RgbColor(0x00, 0x00, 0x8B)),
+    ("darkcyan", RgbColor(0x00, 0x8B, 0x8B)),
+    ("darkgray", RgbColor(0xA9, 0xA9, 0xA9)),
+    ("darkgreen", RgbColor(0x00, 0x64, 0x00)),
+    ("darkgrey", RgbColor(0xA9, 0xA9, 0xA9)),
+    ("darkmagenta", RgbColor(0x8B, 0x00, 0x8B)),
+    ("darkred", RgbColor(0x8B, 0x00, 0x00)),
+    ("gray", RgbColor(0xBE, 0xBE, 0xBE)),
+    ("green", RgbColor(0x00, 0xFF, 0x00)),
+    ("grey", RgbColor(0xBE, 0xBE, 0xBE)),
+    ("grey40", RgbColor(0x66, 0x66, 0x66)),
+    ("grey50", RgbColor(0x7F, 0x7F, 0x7F)),
+    ("grey90", RgbColor(0xE5, 0xE5, 0xE5)),
+    ("lightblue", RgbColor(0xAD, 0xD8, 0xE6)),
+    ("lightcyan", RgbColor(0xE0, 0xFF, 0xFF)),
+    ("lightgray", RgbColor(0xD3, 0xD3, 0xD3)),
+    ("lightgreen", RgbColor(0x90, 0xEE, 0x90)),
+    ("lightgrey", RgbColor(0xD3, 0xD3, 0xD3)),
+    ("lightyellow", RgbColor(0xFF, 0xFF, 0xE0)),
+    ("magenta", RgbColor(0xFF, 0x00, 0xFF)),
+    ("orange", RgbColor(0xFF, 0xA5, 0x00)),
+    ("purple", RgbColor(0xA0, 0x20, 0xF0)),
+    ("red", RgbColor(0xFF, 0x00, 0x00)),
+    ("seagreen", RgbColor(0x2E, 0x8B, 0x57)),
+    ("slateblue", RgbColor(0x6A, 0x5A, 0xCD)),
+    ("violet", RgbColor(0xEE, 0x82, 0xEE)),
+    ("white", RgbColor(0xFF, 0xFF, 0xFF)),
+    ("yellow", RgbColor(0xFF, 0xFF, 0x00)),
+];
+
 pub(crate) fn strip_nocase_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
     let (pre, post) = s.split_at_checked(prefix.len())?;
     pre.eq_ignore_ascii_case(prefix).then_some(post)
 }
 
+/// Parse the digits of an `rrggbb` or `rgb` hex color
+pub(crate) fn parse_hex_rgb(hex: &str) -> Option<RgbColor> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        6 => Some(RgbColor(
+            channel(hex.get(0..2)?)?,
+            channel(hex.get(2..4)?)?,
+            channel(hex.get(4..6)?)?,
+        )),
+        3 => Some(RgbColor(
+            channel(hex.get(0..1)?)? * 0x11,
+            channel(hex.get(1..2)?)? * 0x11,
+            channel(hex.get(2..3)?)? * 0x11,
+        )),
+        _ => None,
+    }
+}
+
+/// Look up an X11 color name.  As in X11, names are matched
+/// case-insensitively and spaces are ignored, so `"Dark Blue"` and
+/// `"darkblue"` are equivalent.
+pub(crate) fn x11_color(name: &str) -> Option<RgbColor> {
+    let key = name.bytes().filter(|&b| b != b' ');
+    X11_COLORS
+        .iter()
+        .find(|(n, _)| key.clone().map(|b| b.to_ascii_lowercase()).eq(n.bytes()))
+        .map(|&(_, rgb)| rgb)
+}
+
+/// Round a floating-point color component to the nearest integer in
+/// `0..=255`
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "value is clamped to the range of u8 first"
+)]
+pub(crate) fn component_from_f64(x: f64) -> u8 {
+    x.round().clamp(0.0, 255.0) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +99,27 @@ mod tests {
     fn test_strip_nocase_prefix(#[case] s: &str, #[case] prefix: &str, #[case] r: Option<&str>) {
         assert_eq!(strip_nocase_prefix(s, prefix), r);
     }
+
+    #[rstest]
+    #[case("red", Some(RgbColor(0xFF, 0x00, 0x00)))]
+    #[case("SeaGreen", Some(RgbColor(0x2E, 0x8B, 0x57)))]
+    #[case("dark blue", Some(RgbColor(0x00, 0x00, 0x8B)))]
+    #[case("Grey50", Some(RgbColor(0x7F, 0x7F, 0x7F)))]
+    #[case("darkyellow", None)]
+    #[case("", None)]
+    fn test_x11_color(#[case] name: &str, #[case] rgb: Option<RgbColor>) {
+        assert_eq!(x11_color(name), rgb);
+    }
+
+    #[rstest]
+    #[case(0.0, 0)]
+    #[case(127.4, 127)]
+    #[case(127.5, 128)]
+    #[case(255.0, 255)]
+    #[case(-3.0, 0)]
+    #[case(300.0, 255)]
+    #[case(f64::NAN, 0)]
+    fn test_component_from_f64(#[case] x: f64, #[case] c: u8) {
+        assert_eq!(component_from_f64(x), c);
+    }
 }
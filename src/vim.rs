@@ -0,0 +1,497 @@
+use crate::attributes::{Attribute, AttributeSet};
+use crate::color::Color;
+use crate::color256::Color256;
+use crate::palette::Palette;
+use crate::rgbcolor::RgbColor;
+use crate::style::Style;
+use crate::util::x11_color;
+use std::fmt;
+use thiserror::Error;
+
+/// The color depth of a terminal or other display
+///
+/// This is used by [`Style::parse_vim_highlight()`] to choose between the
+/// fields of a highlight group that apply to terminals with indexed colors
+/// and the fields that apply to displays with 24-bit colors.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ColorDepth {
+    /// 16 or 256 indexed colors
+    Indexed,
+
+    /// 24-bit RGB colors
+    TrueColor,
+}
+
+/// Vim's names for the `ctermfg` and `ctermbg` colors, along with their color
+/// indices
+static CTERM_COLORS: [(&str, u8); 27] = [
+    ("Black", 0),
+    ("DarkBlue", 4),
+    ("DarkGreen", 2),
+    ("DarkCyan", 6),
+    ("DarkRed", 1),
+    ("DarkMagenta", 5),
+    ("Brown", 3),
+    ("DarkYellow", 3),
+    ("Gray", 7),
+    ("Grey", 7),
+    ("LightGray", 7),
+    ("LightGrey", 7),
+    ("DarkGray", 8),
+    ("DarkGrey", 8),
+    ("Blue", 12),
+    ("LightBlue", 12),
+    ("Green", 10),
+    ("LightGreen", 10),
+    ("Cyan", 14),
+    ("LightCyan", 14),
+    ("Red", 9),
+    ("LightRed", 9),
+    ("Magenta", 13),
+    ("LightMagenta", 13),
+    ("Yellow", 11),
+    ("LightYellow", 11),
+    ("White", 15),
+];
+
+/// Vim's built-in GUI color names that are not also X11 color names, along
+/// with their RGB values
+static VIM_GUI_COLORS: [(&str, RgbColor); 3] = [
+    ("DarkYellow", RgbColor(0x8B, 0x8B, 0x00)),
+    ("LightMagenta", RgbColor(0xFF, 0x8B, 0xFF)),
+    ("LightRed", RgbColor(0xFF, 0x8B, 0x8B)),
+];
+
+/// Color values that refer to the colors of the `Normal` highlight group
+static NORMAL_COLORS: [&str; 4] = ["fg", "bg", "foreground", "background"];
+
+/// Vim's highlight attributes, along with the attributes they are parsed as
+static VIM_ATTRIBUTES: [(&str, Option<Attribute>); 13] = [
+    ("bold", Some(Attribute::Bold)),
+    ("underline", Some(Attribute::Underline)),
+    ("undercurl", Some(Attribute::Underline)),
+    ("underdouble", Some(Attribute::Underline2)),
+    ("underdotted", Some(Attribute::Underline)),
+    ("underdashed", Some(Attribute::Underline)),
+    ("strikethrough", Some(Attribute::Strike)),
+    ("reverse", Some(Attribute::Reverse)),
+    ("inverse", Some(Attribute::Reverse)),
+    ("italic", Some(Attribute::Italic)),
+    ("standout", Some(Attribute::Reverse)),
+    ("nocombine", None),
+    ("NONE", None),
+];
+
+/// Highlight arguments that do not affect the style and are ignored when
+/// parsing
+static IGNORED_KEYS: [&str; 7] = ["term", "start", "stop", "ctermul", "guisp", "font", "blend"];
+
+impl Style {
+    /// Parse the arguments of a Vim or Neovim `:highlight` command, such as
+    /// `cterm=bold ctermfg=208 guifg=#ff8700 gui=italic,undercurl`
+    ///
+    /// If `depth` is [`ColorDepth::Indexed`], the style is taken from the
+    /// `cterm`, `ctermfg`, and `ctermbg` arguments; if it is
+    /// [`ColorDepth::TrueColor`], the style is taken from the `gui`, `guifg`,
+    /// and `guibg` arguments.  The arguments for the other color depth are
+    /// still validated.  The `term`, `start`, `stop`, `ctermul`, `guisp`,
+    /// `font`, and `blend` arguments are ignored.
+    ///
+    /// The arguments may be preceded by `hi` or `highlight` (optionally
+    /// followed by `!` and/or `default`) and a group name, and a lone `NONE`
+    /// argument is parsed as an empty style.
+    ///
+    /// `cterm` and `gui` take a comma-separated list of attributes, which are
+    /// mapped as follows:
+    ///
+    /// - `bold` → [`Attribute::Bold`]
+    /// - `italic` → [`Attribute::Italic`]
+    /// - `underline`, `undercurl`, `underdotted`, and `underdashed` →
+    ///   [`Attribute::Underline`]
+    /// - `underdouble` → [`Attribute::Underline2`]
+    /// - `strikethrough` → [`Attribute::Strike`]
+    /// - `reverse`, `inverse`, and `standout` → [`Attribute::Reverse`]
+    /// - `nocombine` and `NONE` are ignored
+    ///
+    /// `ctermfg` and `ctermbg` take a color number from 0 to 255 or one of
+    /// Vim's color names (e.g., `DarkBlue` or `LightRed`), while `guifg` and
+    /// `guibg` take a `#rrggbb` hex color or one of the color names that Vim
+    /// recognizes on every platform (e.g., `Red`, `SeaGreen`, or `Grey50`),
+    /// which are resolved to RGB colors the same way Vim does.  All four also
+    /// accept `NONE`, which is parsed as [`Color::Default`].  Color names are
+    /// case-insensitive.
+    ///
+    /// The color values `fg`, `bg`, `foreground`, and `background` refer to
+    /// the colors of the `Normal` highlight group, which are not known here,
+    /// and so arguments with these values are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an argument is not of the form `KEY=VALUE`, if a
+    /// key is not recognized, or if a value is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use parse_style::{Color256, ColorDepth, RgbColor, Style};
+    ///
+    /// let hl = "cterm=bold ctermfg=208 guifg=#ff8700 gui=italic,undercurl";
+    /// assert_eq!(
+    ///     Style::parse_vim_highlight(hl, ColorDepth::Indexed).unwrap(),
+    ///     Color256(208).as_foreground().bold(),
+    /// );
+    /// assert_eq!(
+    ///     Style::parse_vim_highlight(hl, ColorDepth::TrueColor).unwrap(),
+    ///     RgbColor(0xff, 0x87, 0x00).as_foreground().italic().underline(),
+    /// );
+    /// ```
+    pub fn parse_vim_highlight(
+        s: &str,
+        depth: ColorDepth,
+    ) -> Result<Style, ParseVimHighlightError> {
+        let mut args = s.split_whitespace().peekable();
+        if args
+            .peek()
+            .is_some_and(|&arg| matches!(arg.strip_suffix('!').unwrap_or(arg), "hi" | "highlight"))
+        {
+            args.next();
+            if args.peek() == Some(&"default") {
+                args.next();
+            }
+            if args.peek().is_some_and(|arg| !arg.contains('=')) {
+                args.next();
+            }
+        }
+        let mut cterm = Style::new();
+        let mut gui = Style::new();
+        for arg in args {
+            if arg == "NONE" {
+                continue;
+            }
+            let Some((key, value)) = arg.split_once('=') else {
+                return Err(ParseVimHighlightError::Argument(arg.to_owned()));
+            };
+            let bad_value = || ParseVimHighlightError::Value {
+                key: key.to_owned(),
+                value: value.to_owned(),
+            };
+            match key {
+                "ctermfg" | "ctermbg" | "guifg" | "guibg"
+                    if NORMAL_COLORS.iter().any(|c| c.eq_ignore_ascii_case(value)) => {}
+                "cterm" => {
+                    cterm =
+                        cterm.enabled_attributes(parse_attributes(value).ok_or_else(bad_value)?);
+                }
+                "gui" => {
+                    gui = gui.enabled_attributes(parse_attributes(value).ok_or_else(bad_value)?);
+                }
+                "ctermfg" => {
+                    cterm = cterm.foreground(Some(parse_cterm_color(value).ok_or_else(bad_value)?));
+                }
+                "ctermbg" => {
+                    cterm = cterm.background(Some(parse_cterm_color(value).ok_or_else(bad_value)?));
+                }
+                "guifg" => {
+                    gui = gui.foreground(Some(parse_gui_color(value).ok_or_else(bad_value)?));
+                }
+                "guibg" => {
+                    gui = gui.background(Some(parse_gui_color(value).ok_or_else(bad_value)?));
+                }
+                _ if IGNORED_KEYS.contains(&key) => (),
+                _ => return Err(ParseVimHighlightError::Key(key.to_owned())),
+            }
+        }
+        Ok(match depth {
+            ColorDepth::Indexed => cterm,
+            ColorDepth::TrueColor => gui,
+        })
+    }
+
+    /// Return a value that displays the style as the arguments of a Vim
+    /// `:highlight` command; see [`VimHighlight`]
+    pub fn display_vim(self) -> VimHighlight {
+        VimHighlight(self)
+    }
+}
+
+fn parse_attributes(value: &str) -> Option<AttributeSet> {
+    value
+        .split(',')
+        .try_fold(AttributeSet::EMPTY, |attrs, name| {
+            let &(_, attr) = VIM_ATTRIBUTES
+                .iter()
+                .find(|&&(n, _)| n.eq_ignore_ascii_case(name))?;
+            Some(attr.map_or(attrs, |a| attrs | a))
+        })
+}
+
+fn parse_cterm_color(value: &str) -> Option<Color> {
+    if value == "NONE" {
+        Some(Color::Default)
+    } else if let Ok(index) = value.parse::<u8>() {
+        Some(Color256(index).into())
+    } else {
+        CTERM_COLORS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(value))
+            .map(|&(_, index)| Color256(index).into())
+    }
+}
+
+fn parse_gui_color(value: &str) -> Option<Color> {
+    if value == "NONE" {
+        Some(Color::Default)
+    } else if value.starts_with('#') {
+        RgbColor::parse_str(value).map(Color::from)
+    } else {
+        VIM_GUI_COLORS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(value))
+            .map(|&(_, rgb)| rgb)
+            .or_else(|| x11_color(value))
+            .map(Color::from)
+    }
+}
+
+/// A wrapper around a [`Style`] that displays it as the arguments of a Vim
+/// `:highlight` command
+///
+/// Both the `cterm` and `gui` arguments are written, so that the result can
+/// be used regardless of whether `'termguicolors'` is set.  [`Color256`]
+/// colors are written to `ctermfg`/`ctermbg` as-is and to `guifg`/`guibg` as
+/// hex colors using the [`Palette::XTERM`] palette, while [`RgbColor`]
+/// colors are written only to `guifg`/`guibg`.  [`Color::Default`] is
+/// written as `NONE`.  An empty style is written as just `NONE`.
+///
+/// # Data Loss
+///
+/// [`Attribute::Underline2`] is written as `underdouble`.  Disabled
+/// attributes and the following enabled attributes are omitted, as Vim has no
+/// equivalents for them:
+///
+/// - [`Attribute::Dim`]
+/// - [`Attribute::Blink`]
+/// - [`Attribute::Blink2`]
+/// - [`Attribute::Conceal`]
+/// - [`Attribute::Frame`]
+/// - [`Attribute::Encircle`]
+/// - [`Attribute::Overline`]
+///
+/// # Example
+///
+/// ```
+/// use parse_style::{Color256, RgbColor, Style};
+///
+/// let style = Color256(208).on(RgbColor(0x12, 0x34, 0x56)).bold().italic();
+/// assert_eq!(
+///     style.display_vim().to_string(),
+///     "cterm=bold,italic gui=bold,italic ctermfg=208 guifg=#ff8700 guibg=#123456",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct VimHighlight(Style);
+
+impl VimHighlight {
+    /// Return the wrapped `Style`
+    pub const fn style(self) -> Style {
+        self.0
+    }
+}
+
+impl From<Style> for VimHighlight {
+    fn from(value: Style) -> VimHighlight {
+        VimHighlight(value)
+    }
+}
+
+impl fmt::Display for VimHighlight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.0;
+        let mut args = Vec::new();
+        let attrs = style
+            .get_enabled_attributes()
+            .into_iter()
+            .filter_map(|attr| match attr {
+                Attribute::Bold => Some("bold"),
+                Attribute::Italic => Some("italic"),
+                Attribute::Underline => Some("underline"),
+                Attribute::Underline2 => Some("underdouble"),
+                Attribute::Reverse => Some("reverse"),
+                Attribute::Strike => Some("strikethrough"),
+                Attribute::Dim
+                | Attribute::Blink
+                | Attribute::Blink2
+                | Attribute::Conceal
+                | Attribute::Frame
+                | Attribute::Encircle
+                | Attribute::Overline => None,
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        if !attrs.is_empty() {
+            args.push(format!("cterm={attrs}"));
+            args.push(format!("gui={attrs}"));
+        }
+        for (color, cterm_key, gui_key) in [
+            (style.get_foreground(), "ctermfg", "guifg"),
+            (style.get_background(), "ctermbg", "guibg"),
+        ] {
+            match color {
+                Some(Color::Default) => {
+                    args.push(format!("{cterm_key}=NONE"));
+                    args.push(format!("{gui_key}=NONE"));
+                }
+                Some(Color::Color256(c)) => {
+                    args.push(format!("{cterm_key}={}", c.0));
+                    args.push(format!("{gui_key}={}", Palette::XTERM.get(c)));
+                }
+                Some(Color::Rgb(rgb)) => args.push(format!("{gui_key}={rgb}")),
+                None => (),
+            }
+        }
+        if args.is_empty() {
+            write!(f, "NONE")
+        } else {
+            write!(f, "{}", args.join(" "))
+        }
+    }
+}
+
+/// Error returned when parsing the arguments of a Vim `:highlight` command
+/// fails
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseVimHighlightError {
+    /// An argument was not of the form `KEY=VALUE`
+    #[error("invalid highlight argument: {0:?}")]
+    Argument(
+        /// The invalid argument
+        String,
+    ),
+
+    /// An argument's key was not recognized
+    #[error("unrecognized highlight key: {0:?}")]
+    Key(
+        /// The unrecognized key
+        String,
+    ),
+
+    /// An argument's value was invalid
+    #[error("invalid value for highlight key {key:?}: {value:?}")]
+    Value {
+        /// The key
+        key: String,
+
+        /// The invalid value
+        value: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", Style::new(), Style::new())]
+    #[case("NONE", Style::new(), Style::new())]
+    #[case(
+        "hi Comment ctermfg=DarkGrey guifg=#808080 cterm=italic gui=italic",
+        Color256::BRIGHT_BLACK.as_foreground().italic(),
+        RgbColor(0x80, 0x80, 0x80).as_foreground().italic()
+    )]
+    #[case(
+        "highlight! default Normal ctermfg=NONE ctermbg=236 guifg=NONE guibg=#303030",
+        Color::Default.on(Color256(236)),
+        Color::Default.on(RgbColor(0x30, 0x30, 0x30))
+    )]
+    #[case(
+        "cterm=bold,underline,NONE gui=undercurl,nocombine,inverse guisp=#ff0000 term=bold",
+        Style::new().bold().underline(),
+        Style::new().underline().reverse()
+    )]
+    #[case(
+        "cterm=underdouble,strikethrough,standout ctermfg=lightred",
+        Color256::BRIGHT_RED.as_foreground().underline2().strike().reverse(),
+        Style::new()
+    )]
+    #[case(
+        "ctermfg=Yellow ctermbg=LightYellow cterm=BOLD,Italic gui=NONE",
+        Color256::BRIGHT_YELLOW.on(Color256::BRIGHT_YELLOW).bold().italic(),
+        Style::new()
+    )]
+    #[case(
+        "ctermfg=fg ctermbg=Black guifg=Red guibg=bg",
+        Color256::BLACK.as_background(),
+        RgbColor(0xFF, 0x00, 0x00).as_foreground()
+    )]
+    #[case(
+        "hi Visual ctermbg=242 guifg=FOREGROUND guibg=DarkYellow",
+        Color256(242).as_background(),
+        RgbColor(0x8B, 0x8B, 0x00).as_background()
+    )]
+    #[case(
+        "guifg=white guibg=SeaGreen gui=bold",
+        Style::new(),
+        RgbColor(0xFF, 0xFF, 0xFF).on(RgbColor(0x2E, 0x8B, 0x57)).bold()
+    )]
+    fn parse(#[case] s: &str, #[case] cterm: Style, #[case] gui: Style) {
+        assert_eq!(
+            Style::parse_vim_highlight(s, ColorDepth::Indexed),
+            Ok(cterm)
+        );
+        assert_eq!(
+            Style::parse_vim_highlight(s, ColorDepth::TrueColor),
+            Ok(gui)
+        );
+    }
+
+    #[rstest]
+    #[case("bold", ParseVimHighlightError::Argument("bold".into()))]
+    #[case("hi Normal Comment", ParseVimHighlightError::Argument("Comment".into()))]
+    #[case("fg=red", ParseVimHighlightError::Key("fg".into()))]
+    #[case(
+        "cterm=bold,blink",
+        ParseVimHighlightError::Value { key: "cterm".into(), value: "bold,blink".into() }
+    )]
+    #[case(
+        "ctermfg=256",
+        ParseVimHighlightError::Value { key: "ctermfg".into(), value: "256".into() }
+    )]
+    #[case(
+        "guifg=Redd",
+        ParseVimHighlightError::Value { key: "guifg".into(), value: "Redd".into() }
+    )]
+    #[case(
+        "guibg=#12345",
+        ParseVimHighlightError::Value { key: "guibg".into(), value: "#12345".into() }
+    )]
+    fn parse_error(#[case] s: &str, #[case] err: ParseVimHighlightError) {
+        assert_eq!(Style::parse_vim_highlight(s, ColorDepth::Indexed), Err(err));
+    }
+
+    #[rstest]
+    #[case(Style::new(), "NONE")]
+    #[case(Style::new().dim().not_bold(), "NONE")]
+    #[case(
+        Color256::RED.on(Color::Default).underline2(),
+        "cterm=underdouble gui=underdouble ctermfg=1 guifg=#800000 ctermbg=NONE guibg=NONE"
+    )]
+    #[case(
+        RgbColor(1, 2, 3).as_foreground().reverse().strike(),
+        "cterm=reverse,strikethrough gui=reverse,strikethrough guifg=#010203"
+    )]
+    fn display(#[case] style: Style, #[case] s: &str) {
+        assert_eq!(style.display_vim().to_string(), s);
+    }
+
+    #[rstest]
+    #[case(Color256(42).on(Color256::BLUE).bold().underline())]
+    #[case(Color::Default.as_background().italic())]
+    fn roundtrip_indexed(#[case] style: Style) {
+        let s = style.display_vim().to_string();
+        assert_eq!(
+            Style::parse_vim_highlight(&s, ColorDepth::Indexed),
+            Ok(style)
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use crate::sgr::{effective, transition_sgr};
+use crate::style::Style;
+use std::fmt;
+use std::io;
+
+/// A writer that styles the text written through it with ANSI escape
+/// sequences
+///
+/// A `StyledWriter` wraps an [`io::Write`] or [`fmt::Write`] value and keeps
+/// track of a current [`Style`].  Before text is written, the writer emits the
+/// minimal SGR sequence needed to change the style of the text on the
+/// terminal to the current style.  Style changes that are not followed by any
+/// text are never emitted.
+///
+/// Styles can either be set outright with [`StyledWriter::set_style()`] or
+/// nested with [`StyledWriter::push_style()`] and
+/// [`StyledWriter::pop_style()`]; a pushed style is [patched][Style::patch]
+/// onto the style that was current before it.
+///
+/// The terminal style is reset when the writer is flushed (for
+/// `io::Write` writers) and when it is dropped.  Errors that occur while
+/// resetting on drop are ignored.
+///
+/// If color is disabled with [`StyledWriter::color()`], no escape sequences
+/// are written at all, and text is passed through unchanged.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Write;
+/// use parse_style::{Color256, Style, StyledWriter};
+///
+/// let mut out = String::new();
+/// {
+///     let mut w = StyledWriter::new_fmt(&mut out);
+///     w.push_style(Color256::RED);
+///     write!(w, "red ").unwrap();
+///     w.push_style(Style::new().bold());
+///     write!(w, "bold red").unwrap();
+///     w.pop_style();
+///     write!(w, " red").unwrap();
+///     w.pop_style();
+///     write!(w, " plain").unwrap();
+/// }
+/// assert_eq!(out, "\x1b[31mred \x1b[1mbold red\x1b[22m red\x1b[0m plain");
+/// ```
+pub struct StyledWriter<W> {
+    inner: W,
+    write_raw: fn(&mut W, &str) -> bool,
+    /// The style currently in effect on the terminal
+    current: Style,
+    /// The style to apply to the next text written
+    style: Style,
+    /// The styles to restore when popping
+    stack: Vec<Style>,
+    color: bool,
+}
+
+impl<W: io::Write> StyledWriter<W> {
+    /// Create a new `StyledWriter` wrapping an [`io::Write`] value, with an
+    /// empty current style and color enabled
+    pub fn new(inner: W) -> StyledWriter<W> {
+        StyledWriter::with_raw_writer(inner, |w, s| w.write_all(s.as_bytes()).is_ok())
+    }
+}
+
+impl<W: fmt::Write> StyledWriter<W> {
+    /// Create a new `StyledWriter` wrapping a [`fmt::Write`] value, with an
+    /// empty current style and color enabled
+    pub fn new_fmt(inner: W) -> StyledWriter<W> {
+        StyledWriter::with_raw_writer(inner, |w, s| w.write_str(s).is_ok())
+    }
+}
+
+impl<W> StyledWriter<W> {
+    fn with_raw_writer(inner: W, write_raw: fn(&mut W, &str) -> bool) -> StyledWriter<W> {
+        StyledWriter {
+            inner,
+            write_raw,
+            current: Style::new(),
+            style: Style::new(),
+            stack: Vec::new(),
+            color: true,
+        }
+    }
+
+    /// Enable or disable emitting escape sequences.  When color is disabled,
+    /// styles are still tracked, but text is written without any styling.
+    pub fn color(mut self, enabled: bool) -> StyledWriter<W> {
+        self.color = enabled;
+        self
+    }
+
+    /// Test whether the writer emits escape sequences
+    pub fn is_color(&self) -> bool {
+        self.color
+    }
+
+    /// Return the style that will be applied to the next text written
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Set the style to apply to subsequently-written text, replacing the
+    /// current style.  This does not affect the styles saved by
+    /// [`StyledWriter::push_style()`].
+    pub fn set_style<S: Into<Style>>(&mut self, style: S) {
+        self.style = style.into();
+    }
+
+    /// Save the current style and then [patch][Style::patch] `style` onto it
+    pub fn push_style<S: Into<Style>>(&mut self, style: S) {
+        self.stack.push(self.style);
+        self.style = self.style.patch(style.into());
+    }
+
+    /// Restore the style saved by the most recent call to
+    /// [`StyledWriter::push_style()`] and return it.  If there are no saved
+    /// styles, the current style is left unchanged, and `None` is returned.
+    pub fn pop_style(&mut self) -> Option<Style> {
+        let style = self.stack.pop()?;
+        self.style = style;
+        Some(style)
+    }
+
+    /// Return a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Return a mutable reference to the inner writer.
+    ///
+    /// Text written directly to the inner writer will be displayed with
+    /// whatever style was last emitted.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Return the escape sequence, if any, needed to change the terminal
+    /// style to `to`, and record `to` as the terminal style
+    fn change_to(&mut self, to: Style) -> Option<String> {
+        if !self.color {
+            return None;
+        }
+        let sgr = transition_sgr(self.current, to)?;
+        self.current = effective(to);
+        Some(sgr)
+    }
+}
+
+impl<W: io::Write> io::Write for StyledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if let Some(sgr) = self.change_to(self.style) {
+            self.inner.write_all(sgr.as_bytes())?;
+        }
+        self.inner.write(buf)
+    }
+
+    /// Reset the terminal style and flush the inner writer.  The current
+    /// style will be re-applied before any further text is written.
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(sgr) = self.change_to(Style::new()) {
+            self.inner.write_all(sgr.as_bytes())?;
+        }
+        self.inner.flush()
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for StyledWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some(sgr) = self.change_to(self.style) {
+            self.inner.write_str(&sgr)?;
+        }
+        self.inner.write_str(s)
+    }
+}
+
+impl<W> Drop for StyledWriter<W> {
+    fn drop(&mut self) {
+        if let Some(sgr) = self.change_to(Style::new()) {
+            let _ = (self.write_raw)(&mut self.inner, &sgr);
+        }
+    }
+}
+
+impl<W: fmt::Debug> fmt::Debug for StyledWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StyledWriter")
+            .field("inner", &self.inner)
+            .field("current", &self.current)
+            .field("style", &self.style)
+            .field("stack", &self.stack)
+            .field("color", &self.color)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color256, RgbColor};
+    use std::fmt::Write as _;
+    use std::io::Write as _;
+
+    #[test]
+    fn io_flush_resets() {
+        let mut out = Vec::new();
+        {
+            let mut w = StyledWriter::new(&mut out);
+            w.set_style(Color256(208).on(RgbColor(1, 2, 3)).underline());
+            w.write_all(b"a").unwrap();
+            w.flush().unwrap();
+            w.write_all(b"b").unwrap();
+            w.set_style(Style::new());
+            w.flush().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[4;38;5;208;48;2;1;2;3ma\x1b[0m\x1b[4;38;5;208;48;2;1;2;3mb\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn drop_resets() {
+        let mut out = String::new();
+        {
+            let mut w = StyledWriter::new_fmt(&mut out);
+            w.set_style(Color256::BRIGHT_BLUE);
+            write!(w, "x").unwrap();
+        }
+        assert_eq!(out, "\x1b[94mx\x1b[0m");
+    }
+
+    #[test]
+    fn unused_style_not_written() {
+        let mut out = String::new();
+        {
+            let mut w = StyledWriter::new_fmt(&mut out);
+            w.set_style(Style::new().bold());
+            w.set_style(Style::new().italic());
+            write!(w, "x").unwrap();
+            w.set_style(Style::new().bold());
+        }
+        assert_eq!(out, "\x1b[3mx\x1b[0m");
+    }
+
+    #[test]
+    fn shared_off() {
+        let mut out = String::new();
+        {
+            let mut w = StyledWriter::new_fmt(&mut out);
+            w.set_style(Style::new().bold().dim().underline2());
+            write!(w, "a").unwrap();
+            w.set_style(Style::new().dim().underline());
+            write!(w, "b").unwrap();
+        }
+        assert_eq!(out, "\x1b[1;2;21ma\x1b[22;24;2;4mb\x1b[0m");
+    }
+
+    #[test]
+    fn push_disables() {
+        let mut out = String::new();
+        {
+            let mut w = StyledWriter::new_fmt(&mut out);
+            w.push_style(Style::new().bold().italic());
+            w.push_style(Style::new().not_italic());
+            write!(w, "a").unwrap();
+            assert_eq!(w.pop_style(), Some(Style::new().bold().italic()));
+            write!(w, "b").unwrap();
+            assert_eq!(w.pop_style(), Some(Style::new()));
+            assert_eq!(w.pop_style(), None);
+            write!(w, "c").unwrap();
+        }
+        assert_eq!(out, "\x1b[1ma\x1b[3mb\x1b[0mc");
+    }
+
+    #[test]
+    fn no_color() {
+        let mut out = String::new();
+        {
+            let mut w = StyledWriter::new_fmt(&mut out).color(false);
+            w.push_style(Color256::RED);
+            write!(w, "plain").unwrap();
+            assert_eq!(w.style(), Style::from(Color256::RED));
+        }
+        assert_eq!(out, "plain");
+    }
+}